@@ -0,0 +1,205 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::axis_typing::{AxisInfo, AxisKindMarker};
+use crate::options::{AxisType, EChartOptions};
+
+/// Data point reduced to its numeric value and the label it is shown at, if any
+struct Point {
+    label: Option<String>,
+    value: f64,
+}
+
+/// Index of the numeric dimension in a data row and of the dimension labelling it
+fn dimensions<X: AxisKindMarker, Y: AxisKindMarker>() -> Option<(usize, Option<usize>)> {
+    let x = X::AxisType::AXIS_TYPE;
+    let y = Y::AxisType::AXIS_TYPE;
+    let label = |axis: &AxisType, index: usize| (*axis == AxisType::Category).then_some(index);
+    if matches!(y, AxisType::Value | AxisType::Log) {
+        Some((1, label(&x, 0)))
+    } else if matches!(x, AxisType::Value | AxisType::Log) {
+        Some((0, label(&y, 1)))
+    } else {
+        None
+    }
+}
+
+fn label_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) if n.is_f64() => n.as_f64().map(|f| f.to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Split a serialized data item: `[x, y]`, `[x, y, name]`, `{value, name}` or a single value,
+/// into its dimensions and name
+fn row(item: &Value) -> Option<(Vec<&Value>, Option<&str>)> {
+    let (row, name) = match item {
+        Value::Object(o) => (o.get("value")?, o.get("name").and_then(Value::as_str)),
+        _ => (item, None),
+    };
+    match row {
+        Value::Array(a) if a.len() > 2 => Some((a[..2].iter().collect(), name.or(a[2].as_str()))),
+        Value::Array(a) => Some((a.iter().collect(), name)),
+        _ => Some((vec![row], name)),
+    }
+}
+
+fn point(item: &Value, value_dim: usize, label_dim: Option<usize>) -> Option<Point> {
+    let (dims, name) = row(item)?;
+    let (value, label) = match dims.len() {
+        1 => (dims[0].as_f64()?, name.map(str::to_string)),
+        _ => (
+            dims.get(value_dim)?.as_f64()?,
+            name.map(str::to_string).or_else(|| label_dim.and_then(|d| dims.get(d)).and_then(|v| label_text(v))),
+        ),
+    };
+    Some(Point { label, value })
+}
+
+/// Data of a serialized series, either inline or from the dataset it refers to
+fn series_data<'a>(options: &'a Value, series: &'a Value) -> Option<&'a Vec<Value>> {
+    match series.get("datasetIndex").and_then(Value::as_u64) {
+        Some(dataset) => options["dataset"][dataset as usize]["source"].as_array(),
+        None => series["data"].as_array(),
+    }
+}
+
+fn format_value(value: f64, label: &Option<String>) -> String {
+    match label {
+        Some(label) => format!("{value} at {label}"),
+        None => format!("{value}"),
+    }
+}
+
+fn describe_series(name: &str, points: &[Point]) -> String {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return format!("{name}: no data.");
+    };
+    // ties are reported at their first occurrence
+    let min = points.iter().fold(first, |min, p| if p.value < min.value { p } else { min });
+    let max = points.iter().fold(first, |max, p| if p.value > max.value { p } else { max });
+    // changes within 1% of the value range are not considered a trend
+    let change = last.value - first.value;
+    let trend = if change.abs() <= (max.value - min.value) * 0.01 {
+        "stable"
+    } else if change > 0.0 {
+        "increasing"
+    } else {
+        "decreasing"
+    };
+    format!(
+        "{name}: {} values, minimum {}, maximum {}, {trend} from {} to {}.",
+        points.len(),
+        format_value(min.value, &min.label),
+        format_value(max.value, &max.label),
+        first.value,
+        last.value,
+    )
+}
+
+/// Textual summary of the chart: title, series names and for each series
+/// its minimum, maximum and the trend from the first to the last value.
+/// Statistics are only given for the numeric axis, series with data in a transformed dataset
+/// are listed by name only
+pub(crate) fn describe<X: AxisKindMarker, Y: AxisKindMarker>(options: &EChartOptions<X,Y>) -> String
+where EChartOptions<X,Y>: Serialize {
+    let mut parts = Vec::new();
+    if let Some(text) = options.title.as_ref().and_then(|t| t.text.as_ref()) {
+        parts.push(format!("{text}."));
+    }
+    let Ok(json) = serde_json::to_value(options) else {
+        return parts.join(" ");
+    };
+    let series = json["series"].as_array().cloned().unwrap_or_default();
+    let names: Vec<&str> = series.iter().filter_map(|s| s["name"].as_str()).collect();
+    match series.len() {
+        0 => parts.push("Chart without data.".to_string()),
+        n => parts.push(format!("Chart with {n} series: {}.", names.join(", "))),
+    }
+    let Some((value_dim, label_dim)) = dimensions::<X,Y>() else {
+        return parts.join(" ");
+    };
+    for (index, s) in series.iter().enumerate() {
+        let name = s["name"].as_str().map(str::to_string).unwrap_or_else(|| format!("Series {}", index + 1));
+        if let Some(data) = series_data(&json, s) {
+            let points: Vec<Point> = data.iter().filter_map(|item| point(item, value_dim, label_dim)).collect();
+            parts.push(describe_series(&name, &points));
+        }
+    }
+    parts.join(" ")
+}
+
+/// Time axis values are serialized as milliseconds since the epoch, shown as ISO 8601 in UTC
+#[cfg(feature = "chrono_axis")]
+fn iso_time(millis: i64) -> Option<String> {
+    chrono::DateTime::from_timestamp_millis(millis)
+        .map(|t| t.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+}
+
+/// Time axis values are serialized as milliseconds since the epoch, shown as ISO 8601 in UTC
+#[cfg(all(feature = "time_axis", not(feature = "chrono_axis")))]
+fn iso_time(millis: i64) -> Option<String> {
+    time::OffsetDateTime::from_unix_timestamp_nanos(millis as i128 * 1_000_000).ok()?
+        .format(&time::format_description::well_known::Rfc3339).ok()
+}
+
+#[cfg(not(any(feature = "time_axis", feature = "chrono_axis")))]
+fn iso_time(_millis: i64) -> Option<String> {
+    None
+}
+
+fn cell_text(value: &Value, axis: &AxisType) -> String {
+    if *axis == AxisType::Time && let Some(time) = value.as_i64().and_then(iso_time) {
+        return time;
+    }
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        Value::Number(n) if n.is_f64() => n.as_f64().unwrap_or_default().to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Chart data as a plain table, one row per data point
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DataTableContent {
+    /// Chart title
+    pub caption: Option<String>,
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Collect the values of all series into a table with the series name, x, y and,
+/// when any data point is named, the point name. Values are formatted by the axis types
+/// of the serialized options, time axis values as ISO 8601 dates
+pub(crate) fn data_table(json: &Value) -> DataTableContent {
+    let caption = json["title"]["text"].as_str().map(str::to_string);
+    let axis_type = |axis: &str| AxisType::deserialize(&json[axis]["type"]).unwrap_or(AxisType::Category);
+    let x_axis = axis_type("xAxis");
+    let y_axis = axis_type("yAxis");
+    let axis_name = |axis: &str, default: &str| json[axis]["name"].as_str().unwrap_or(default).to_string();
+
+    let mut rows = Vec::new();
+    let mut named = false;
+    for (index, s) in json["series"].as_array().into_iter().flatten().enumerate() {
+        let series = s["name"].as_str().map(str::to_string).unwrap_or_else(|| format!("Series {}", index + 1));
+        for item in series_data(json, s).into_iter().flatten() {
+            let Some((dims, name)) = row(item) else { continue };
+            named |= name.is_some();
+            let (x, y) = match dims.as_slice() {
+                [x, y, ..] => (cell_text(x, &x_axis), cell_text(y, &y_axis)),
+                [value] => (String::new(), cell_text(value, &y_axis)),
+                [] => continue,
+            };
+            rows.push(vec![series.clone(), x, y, name.unwrap_or_default().to_string()]);
+        }
+    }
+    let mut headers = vec!["Series".to_string(), axis_name("xAxis", "x"), axis_name("yAxis", "y"), "Name".to_string()];
+    if !named {
+        headers.pop();
+        rows.iter_mut().for_each(|r| { r.pop(); });
+    }
+    DataTableContent { caption, headers, rows }
+}
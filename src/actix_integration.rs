@@ -0,0 +1,108 @@
+use actix_web::body::BoxBody;
+use actix_web::http::header::{self, ContentType};
+use actix_web::{HttpRequest, HttpResponse, Responder};
+use sailfish::{RenderError, TemplateSimple};
+use serde::Serialize;
+use crate::axis_typing::AxisKindMarker;
+use crate::ids::fnv1a;
+use crate::options::EChartOptions;
+use crate::templates::{OnePage, ScriptTemplate};
+
+fn html(rendered: Result<String, RenderError>) -> HttpResponse {
+    match rendered {
+        Ok(html) => HttpResponse::Ok().content_type(ContentType::html()).body(html),
+        Err(error) => HttpResponse::InternalServerError()
+            .content_type(ContentType::plaintext())
+            .body(format!("failed to render chart: {error}")),
+    }
+}
+
+/// Chart as an HTML fragment, e.g. for htmx swaps
+impl<X: AxisKindMarker, Y: AxisKindMarker> Responder for ScriptTemplate<X,Y>
+where EChartOptions<X,Y>: Serialize {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        html(self.render_once())
+    }
+}
+
+/// Complete HTML document
+impl Responder for OnePage<'_> {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        html(self.render_once())
+    }
+}
+
+/// Options as JSON, to be passed to `setOption` by the frontend.
+/// JSON has no functions, so options using [`crate::common::JsFunction`] can't be sent this way
+impl<X: AxisKindMarker, Y: AxisKindMarker> Responder for EChartOptions<X,Y>
+where EChartOptions<X,Y>: Serialize {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        HttpResponse::Ok().json(self)
+    }
+}
+
+/// Response with an `ETag` derived from a hash of the rendered chart or the serialized options.
+/// When the request's `If-None-Match` matches, `304 Not Modified` is returned without a body.
+/// The container id is part of the rendered chart, build it with a stable id
+/// (e.g. [`crate::ids::IdStrategy::Fixed`]) for the tag to match across requests
+///
+/// ```ignore
+/// async fn chart() -> WithETag<ScriptTemplate<&'static str, f64>> {
+///     WithETag(build_chart())
+/// }
+/// ```
+pub struct WithETag<T>(pub T);
+
+fn etag(body: &[u8]) -> String {
+    format!("\"{:016x}\"", fnv1a(body))
+}
+
+/// Whether any of the tags in `If-None-Match` is the current one, weak comparison
+fn not_modified(req: &HttpRequest, etag: &str) -> bool {
+    req.headers()
+        .get_all(header::IF_NONE_MATCH)
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim().trim_start_matches("W/"))
+        .any(|tag| tag == etag || tag == "*")
+}
+
+fn respond_with_etag(req: &HttpRequest, content_type: ContentType, body: String) -> HttpResponse {
+    let etag = etag(body.as_bytes());
+    if not_modified(req, &etag) {
+        return HttpResponse::NotModified().insert_header((header::ETAG, etag)).finish();
+    }
+    HttpResponse::Ok().content_type(content_type).insert_header((header::ETAG, etag)).body(body)
+}
+
+impl<X: AxisKindMarker, Y: AxisKindMarker> Responder for WithETag<ScriptTemplate<X,Y>>
+where EChartOptions<X,Y>: Serialize {
+    type Body = BoxBody;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+        match self.0.render_once() {
+            Ok(html) => respond_with_etag(req, ContentType::html(), html),
+            Err(error) => html(Err(error)),
+        }
+    }
+}
+
+impl<X: AxisKindMarker, Y: AxisKindMarker> Responder for WithETag<EChartOptions<X,Y>>
+where EChartOptions<X,Y>: Serialize {
+    type Body = BoxBody;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+        match serde_json::to_string(&self.0) {
+            Ok(json) => respond_with_etag(req, ContentType::json(), json),
+            Err(error) => HttpResponse::InternalServerError()
+                .content_type(ContentType::plaintext())
+                .body(format!("failed to serialize chart options: {error}")),
+        }
+    }
+}
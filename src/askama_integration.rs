@@ -0,0 +1,21 @@
+use std::fmt;
+use askama::filters::HtmlSafe;
+use askama::{FastWritable, Values};
+use sailfish::TemplateSimple;
+use serde::Serialize;
+use crate::axis_typing::AxisKindMarker;
+use crate::options::EChartOptions;
+use crate::templates::ScriptTemplate;
+
+/// The chart fragment is HTML, `{{ chart }}` is written without escaping
+impl<X,Y> HtmlSafe for ScriptTemplate<X,Y>
+where X: AxisKindMarker + Clone, Y: AxisKindMarker + Clone, EChartOptions<X,Y>: Serialize {}
+
+impl<X,Y> FastWritable for ScriptTemplate<X,Y>
+where X: AxisKindMarker + Clone, Y: AxisKindMarker + Clone, EChartOptions<X,Y>: Serialize {
+    fn write_into<W: fmt::Write + ?Sized>(&self, dest: &mut W, _values: &dyn Values) -> askama::Result<()> {
+        let html = self.clone().render_once().map_err(askama::Error::custom)?;
+        dest.write_str(&html)?;
+        Ok(())
+    }
+}
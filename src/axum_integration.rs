@@ -0,0 +1,41 @@
+use axum::http::StatusCode;
+use axum::response::{Html, IntoResponse, Json, Response};
+use sailfish::TemplateSimple;
+use serde::Serialize;
+use crate::axis_typing::AxisKindMarker;
+use crate::options::EChartOptions;
+use crate::templates::{OnePage, ScriptTemplate};
+
+fn render_error(error: sailfish::RenderError) -> Response {
+    (StatusCode::INTERNAL_SERVER_ERROR, format!("failed to render chart: {error}")).into_response()
+}
+
+/// Chart as an HTML fragment, e.g. for htmx swaps
+impl<X: AxisKindMarker, Y: AxisKindMarker> IntoResponse for ScriptTemplate<X,Y>
+where EChartOptions<X,Y>: Serialize {
+    fn into_response(self) -> Response {
+        match self.render_once() {
+            Ok(html) => Html(html).into_response(),
+            Err(error) => render_error(error),
+        }
+    }
+}
+
+/// Complete HTML document
+impl IntoResponse for OnePage<'_> {
+    fn into_response(self) -> Response {
+        match self.render_once() {
+            Ok(html) => Html(html).into_response(),
+            Err(error) => render_error(error),
+        }
+    }
+}
+
+/// Options as JSON, to be passed to `setOption` by the frontend.
+/// JSON has no functions, so options using [`crate::common::JsFunction`] can't be sent this way
+impl<X: AxisKindMarker, Y: AxisKindMarker> IntoResponse for EChartOptions<X,Y>
+where EChartOptions<X,Y>: Serialize {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
@@ -1,14 +1,18 @@
+use std::collections::HashSet;
 use crate::axis_typing::{AxisKindMarker, ValueAxis};
 use crate::common::Size;
+use crate::ids::IdStrategy;
 use crate::options::*;
-use crate::templates::ScriptTemplate;
+use crate::templates::{DataUpdate, InitOptions, ScriptTemplate};
 use serde::Serialize;
 use serde_json::json;
-use uuid::Uuid;
 use crate::common;
+use crate::accessibility;
 use crate::options::Position::Percent;
+use crate::presets::StylePreset;
 
 ///trait that provides regression methods that are only supported when both x and y are numeric
+#[allow(dead_code)]
 impl<X, Y>  EChartOptions<X,Y>
 where X: AxisKindMarker<AxisType=ValueAxis>+ Serialize,
       Y: AxisKindMarker<AxisType=ValueAxis>+ Serialize,
@@ -75,28 +79,24 @@ where X: AxisKindMarker<AxisType=ValueAxis>+ Serialize,
 
         // Add scatter series for original data
         self.series.as_mut().unwrap().push(Series {
-            r#type: Some(SeriesType::Scatter),
-            name: Some(format!("{} (data)", series_label)),
-            smooth: None,
-            area_style: None,
-            data: SeriesDataSource::DatasetIndex(source_index),
-            show_symbol: None,
             symbol: Some(DataPointSymbol::Circle),
             symbol_size: Some(8),
-            extra: None,
+            ..Series::new(
+                format!("{} (data)", series_label),
+                SeriesType::Scatter,
+                SeriesDataSource::DatasetIndex(source_index)
+            )
         });
 
         // Add line series for regression
         self.series.as_mut().unwrap().push(Series {
-            r#type: Some(SeriesType::Line),
-            name: Some(format!("{} (regression)", series_label)),
             smooth: Some(true),
-            area_style: None,
-            data: SeriesDataSource::DatasetIndex(transform_index),
-            show_symbol: None,
             symbol: Some(DataPointSymbol::None),
-            symbol_size: None,
-            extra: None
+            ..Series::new(
+                format!("{} (regression)", series_label),
+                SeriesType::Line,
+                SeriesDataSource::DatasetIndex(transform_index)
+            )
         });
 
         self
@@ -141,9 +141,16 @@ where X: AxisKindMarker, Y: AxisKindMarker, EChartOptions<X,Y>:Serialize{
                     snap: Some(false),
                     animation: None,
                     axis: None,
-                })
+                }),
+                background_color: None,
+                border_color: None,
+                border_width: None,
+                padding: None,
+                text_style: None,
             }),
             legend: None, grid: None, extra: None, dataset: None,
+            color: None, background_color: None, text_style: None, data_zoom: None, visual_map: None,
+            toolbox: None, brush: None, aria: None,
             x_axis: Axis::default(),
             y_axis: Axis::default(),
             series: Some(Vec::new()),
@@ -167,9 +174,16 @@ where X: AxisKindMarker, Y: AxisKindMarker, EChartOptions<X,Y>:Serialize {
                     snap: Some(false),
                     animation: None,
                     axis: None,
-                })
+                }),
+                background_color: None,
+                border_color: None,
+                border_width: None,
+                padding: None,
+                text_style: None,
             }),
             legend: None, grid: None, extra: None, dataset: None,
+            color: None, background_color: None, text_style: None, data_zoom: None, visual_map: None,
+            toolbox: None, brush: None, aria: None,
             x_axis,
             y_axis,
             series: Some(Vec::new()),
@@ -180,6 +194,7 @@ where X: AxisKindMarker, Y: AxisKindMarker, EChartOptions<X,Y>:Serialize {
     pub fn enable_legend(mut self) -> Self{
         if self.legend.is_none(){
             self.legend = Some(Legend{
+                id: None,
                 data: None,
                 orient: Some(LegendOrient::Vertical),
                 left: None,
@@ -197,6 +212,7 @@ where X: AxisKindMarker, Y: AxisKindMarker, EChartOptions<X,Y>:Serialize {
         }
         if self.grid.is_none(){
             self.grid = Some( Grid{
+                id: None,
                 left: None,
                 right: Some(Percent(common::Percent(20.0))),
                 top: None,
@@ -218,6 +234,99 @@ where X: AxisKindMarker, Y: AxisKindMarker, EChartOptions<X,Y>:Serialize {
         self
     }
 
+    /// Add a dataZoom component bound to the x-axis
+    pub fn add_x_data_zoom(mut self, mut data_zoom: DataZoom<X>) -> Self {
+        data_zoom.x_axis_index = Some(0);
+        data_zoom.y_axis_index = None;
+        self.data_zoom.get_or_insert_default().push(AxisDataZoom::X(data_zoom));
+        self
+    }
+
+    /// Add a dataZoom component bound to the y-axis
+    pub fn add_y_data_zoom(mut self, mut data_zoom: DataZoom<Y>) -> Self {
+        data_zoom.y_axis_index = Some(0);
+        data_zoom.x_axis_index = None;
+        self.data_zoom.get_or_insert_default().push(AxisDataZoom::Y(data_zoom));
+        self
+    }
+
+    /// Zoom the x-axis with the mouse wheel and a slider below the grid.
+    /// Does nothing if the chart already has dataZoom components
+    pub fn enable_zoom(self) -> Self {
+        if self.data_zoom.is_some() {
+            return self;
+        }
+        self.add_x_data_zoom(DataZoom::inside().range_percent(0.0, 100.0))
+            .add_x_data_zoom(DataZoom::slider().range_percent(0.0, 100.0))
+    }
+
+    /// Show a toolbox with image export, read-only data view, area zoom,
+    /// line/bar switching and restore. Keeps the features of an existing toolbox
+    pub fn enable_toolbox(mut self) -> Self {
+        let toolbox = self.toolbox.get_or_insert_default();
+        let feature = &mut toolbox.feature;
+        feature.save_as_image.get_or_insert_with(|| SaveAsImageFeature {
+            pixel_ratio: Some(2.0),
+            ..Default::default()
+        });
+        feature.data_view.get_or_insert_with(|| DataViewFeature {
+            read_only: Some(true),
+            ..Default::default()
+        });
+        feature.data_zoom.get_or_insert_with(|| DataZoomFeature {
+            y_axis_index: Some(json!("none")),
+            ..Default::default()
+        });
+        feature.magic_type.get_or_insert_with(|| MagicTypeFeature {
+            r#type: Some(vec![MagicType::Line, MagicType::Bar]),
+            ..Default::default()
+        });
+        feature.restore.get_or_insert_default();
+        self
+    }
+
+    /// Enable brush selection. The toolbox buttons of the brush are shown when the chart has a toolbox
+    pub fn brush(mut self, brush: Brush) -> Self {
+        self.brush = Some(brush);
+        self
+    }
+
+    /// Enable the aria component with decal patterns for color-blind users
+    pub fn enable_aria(mut self) -> Self {
+        let aria = self.aria.get_or_insert_default();
+        aria.enabled = Some(true);
+        aria.decal.get_or_insert_default().show = Some(true);
+        self
+    }
+
+    /// Set the description read by screen readers.
+    /// It is also rendered as visually hidden text next to the chart
+    pub fn aria_description(mut self, description: String) -> Self {
+        let aria = self.aria.get_or_insert_default();
+        aria.enabled = Some(true);
+        let label = aria.label.get_or_insert_default();
+        label.enabled = Some(true);
+        label.description = Some(description);
+        self
+    }
+
+    /// Generate the aria description from the title and the series data:
+    /// series names, their minimum and maximum and the trend from the first to the last value
+    pub fn describe_data(self) -> Self {
+        let description = accessibility::describe(&self);
+        self.aria_description(description)
+    }
+
+    pub fn add_visual_map(mut self, visual_map: VisualMap) -> Self {
+        self.visual_map.get_or_insert_default().push(visual_map);
+        self
+    }
+
+    /// Apply palette, fonts, grid paddings, tooltip look and legend placement from a preset
+    pub fn apply_preset(self, preset: &StylePreset) -> Self {
+        preset.apply(self)
+    }
+
     /// Set chart title
     pub fn title_str(mut self, title: String) -> Self {
         let t =  self.title.get_or_insert_default();
@@ -258,39 +367,24 @@ where X: AxisKindMarker, Y: AxisKindMarker, EChartOptions<X,Y>:Serialize {
     /// If no datasets exist, or dataset_index is out of range, no datasets will be added
     pub fn add_dataset_visualisation(mut self, series_label:String, series_type: SeriesType, dataset_index: usize) -> Self {
         let datasets = &self.dataset;
-        if let Some(datasets) = datasets {
-            if let Some(dataset) =  datasets.get(dataset_index){
-                match dataset {
-                    DatasetComponent::Source(_) | DatasetComponent::Transform(_) => {
-                        self.series.as_mut().unwrap().push(Series {
-                            r#type: Some(series_type),
-                            name: Some(series_label),
-                            smooth: Some(true),
-                            area_style: None,
-                            symbol: None,
-                            symbol_size: None,
-                            data: SeriesDataSource::DatasetIndex(dataset_index),
-                            extra: None,
-                            show_symbol: None,
-                        });
-                    }
-                    DatasetComponent::LabelledSource(_) => {
-                        self.series.as_mut().unwrap().push(Series {
-                            r#type: Some(series_type),
-                            name: Some(series_label),
-                            smooth: Some(true),
-                            area_style: None,
-                            symbol: None,
-                            symbol_size: None,
-                            data: SeriesDataSource::DatasetIndex(dataset_index),
-                            extra: Some(json!(
-                               {"encode": {"tooltip": [2,1], "x": 0, "y": 1 }}
-                           )),
-                            show_symbol: None,
-                        });
-                    }
+        if let Some(datasets) = datasets
+            && let Some(dataset) =  datasets.get(dataset_index){
+            match dataset {
+                DatasetComponent::Source(_) | DatasetComponent::Transform(_) => {
+                    self.series.as_mut().unwrap().push(Series {
+                        smooth: Some(true),
+                        ..Series::new(series_label, series_type, SeriesDataSource::DatasetIndex(dataset_index))
+                    });
+                }
+                DatasetComponent::LabelledSource(_) => {
+                    self.series.as_mut().unwrap().push(Series {
+                        smooth: Some(true),
+                        extra: Some(json!(
+                           {"encode": {"tooltip": [2,1], "x": 0, "y": 1 }}
+                       )),
+                        ..Series::new(series_label, series_type, SeriesDataSource::DatasetIndex(dataset_index))
+                    });
                 }
-
             }
         }
         self
@@ -310,7 +404,59 @@ where X: AxisKindMarker, Y: AxisKindMarker, EChartOptions<X,Y>:Serialize {
         self
     }
 
+    /// Give every series without an id one derived from its name, e.g. `"Sales 2024"` becomes `"sales-2024"`,
+    /// so that later `setOption` updates are matched to the same series. Repeated names get a `-2`, `-3`...
+    /// suffix, unnamed series are `series-{index}`
+    pub fn auto_ids(mut self) -> Self {
+        let series = self.series.get_or_insert_with(Vec::new);
+        let mut taken: HashSet<String> = series.iter().filter_map(|s| s.id.clone()).collect();
+        for (index, s) in series.iter_mut().enumerate().filter(|(_, s)| s.id.is_none()) {
+            let base = match s.name.as_deref().map(slug) {
+                Some(slug) if !slug.is_empty() => slug,
+                _ => format!("series-{index}"),
+            };
+            let id = (1..).map(|n| if n == 1 { base.clone() } else { format!("{base}-{n}") })
+                .find(|id| !taken.contains(id))
+                .unwrap_or(base);
+            taken.insert(id.clone());
+            s.id = Some(id);
+        }
+        self
+    }
+
+    /// Chart with the next container id of a process wide counter, `echarts-{n}`, unique on the page.
+    /// For stable markup, e.g. in snapshots, see [`Self::build_with_id_strategy`]
     pub fn build(self, width: Size, height: Size) -> ScriptTemplate<X,Y>{
-        ScriptTemplate::new(Uuid::new_v4().to_string(), width, height, self)
+        self.build_with_id_strategy(IdStrategy::Unique, width, height)
+    }
+
+    /// Build with the container id chosen by the strategy, e.g. a counter for unique ids on a page
+    pub fn build_with_id_strategy(self, ids: IdStrategy, width: Size, height: Size) -> ScriptTemplate<X,Y>{
+        ScriptTemplate::new(ids.id(&self, &width, &height), width, height, self)
+    }
+
+    /// Build for htmx swaps with a stable id, see [`ScriptTemplate::htmx`]
+    pub fn build_htmx(self, chart_target_id: &str, width: Size, height: Size) -> ScriptTemplate<X,Y>{
+        ScriptTemplate::new(chart_target_id.to_string(), width, height, self).htmx()
     }
+
+    /// Build an out-of-band fragment updating only the data of the htmx chart with the given id
+    pub fn build_data_update(self, chart_target_id: &str) -> DataUpdate<X,Y>
+    where DatasetComponent<X,Y>: Serialize {
+        DataUpdate::new(chart_target_id.to_string(), self)
+    }
+
+    /// Build with renderer, device pixel ratio and locale passed to `echarts.init`
+    pub fn build_with_init_options(self, width: Size, height: Size, init_options: InitOptions) -> ScriptTemplate<X,Y>{
+        self.build(width, height).init_options(init_options)
+    }
+}
+
+/// Lowercase name with runs of other characters than letters and digits replaced by a single dash
+fn slug(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
 }
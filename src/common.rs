@@ -1,9 +1,13 @@
 use std::fmt;
+use std::hash::{BuildHasher, RandomState};
+use std::sync::LazyLock;
 use std::fmt::Write;
+use std::str::FromStr;
 use sailfish::RenderError;
 use sailfish::runtime::{Buffer, Render};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde::de::Visitor;
+use serde_json::Value;
 
 /// Newtype for percentage values, serialized as "{value}%"
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -68,14 +72,30 @@ impl Render for Size{
     }
 }
 
-impl Size{
-    pub fn to_string(&self) -> String{
+impl fmt::Display for Size{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self{
-            Size::Percent(p) => format!("{}%", p.0),
-            Size::Pixel(p) => format!("{}px", p.0)
+            Size::Percent(p) => write!(f, "{}%", p.0),
+            Size::Pixel(p) => write!(f, "{}px", p.0)
         }
     }
+}
+
+/// Parse "50%" as percent, "400px" or "400" as pixels
+impl FromStr for Size {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let parsed = match s.strip_suffix('%') {
+            Some(percent) => percent.trim().parse::<f32>().ok().map(Size::percent),
+            None => s.trim_end_matches("px").trim().parse::<usize>().ok().map(Size::pixels),
+        };
+        parsed.ok_or_else(|| format!("invalid size \"{s}\", expected e.g. \"400px\" or \"50%\""))
+    }
+}
 
+impl Size{
     pub fn percent(f: f32) -> Self{
         Size::Percent(Percent(f))
     }
@@ -138,4 +158,107 @@ impl<'de> Deserialize<'de> for Rgb {
 
         deserializer.deserialize_str(RgbVisitor)
     }
-}
\ No newline at end of file
+}
+
+/// Prefix that marks JSON strings holding JS code, serialized by serde_json as "\u0001js:"
+const JS_FUNCTION_MARKER: &str = "\u{1}js:";
+
+/// Key of the hash tagging [`JsFunction`] strings, random per process,
+/// so that strings coming with the chart data can't pass as code
+static JS_FUNCTION_KEY: LazyLock<RandomState> = LazyLock::new(RandomState::new);
+
+/// Raw JavaScript emitted into the option object, e.g. a formatter callback.
+/// Serialized as a string tagged with the marker and a keyed hash of the code, that the chart template
+/// replaces with the code itself. Strings that aren't tagged by this process are always emitted as strings.
+/// Can be used in any `Value` field: `formatter: Some(JsFunction::new("p => p.value + ' ms'").into())`.
+/// Other consumers of the serialized JSON see the tagged string, not a function
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsFunction(pub String);
+
+impl JsFunction {
+    pub fn new(code: &str) -> Self {
+        JsFunction(code.to_string())
+    }
+
+    fn tagged(&self) -> String {
+        format!("{JS_FUNCTION_MARKER}{:016x}:{}", JS_FUNCTION_KEY.hash_one(&self.0), self.0)
+    }
+
+    /// Code of a string produced by [`JsFunction::tagged`], `None` for any other string
+    fn untag(text: &str) -> Option<&str> {
+        let (hash, code) = text.strip_prefix(JS_FUNCTION_MARKER)?.split_once(':')?;
+        let hash = u64::from_str_radix(hash, 16).ok()?;
+        (JS_FUNCTION_KEY.hash_one(code) == hash).then_some(code)
+    }
+}
+
+impl Serialize for JsFunction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        serializer.serialize_str(&self.tagged())
+    }
+}
+
+impl From<JsFunction> for Value {
+    fn from(value: JsFunction) -> Self {
+        Value::String(value.tagged())
+    }
+}
+
+/// Make text safe to put into a script element: `</` is written as `<\/`,
+/// which means the same in JSON and JS strings but can't close the element
+pub(crate) fn escape_script(text: &str) -> String {
+    text.replace("</", "<\\/")
+}
+
+/// Replace the JSON string values produced by [`JsFunction`] with the raw code they hold.
+/// Strings used as object keys and strings with a tag of another process are left as they are
+pub(crate) fn substitute_js_functions(json: &str) -> Result<String, serde_json::Error> {
+    let needle = "\"\\u0001js:";
+    let mut result = String::with_capacity(json.len());
+    let mut rest = json;
+    while let Some(start) = rest.find(needle) {
+        result.push_str(&rest[..start]);
+        let literal = &rest[start..];
+        let mut end = 1;
+        let bytes = literal.as_bytes();
+        while bytes[end] != b'"' {
+            end += if bytes[end] == b'\\' { 2 } else { 1 };
+        }
+        let text: String = serde_json::from_str(&literal[..=end])?;
+        let after = &literal[end + 1..];
+        let is_key = after.trim_start().starts_with(':');
+        match JsFunction::untag(&text) {
+            Some(code) if !is_key => result.push_str(code),
+            _ => result.push_str(&literal[..=end]),
+        }
+        rest = after;
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// Renders the value as JSON into a script element, escaped with [`escape_script`].
+/// Every value the templates interpolate into a script goes through this or [`JsonWithFunctions`]
+pub struct ScriptJson<'a, T: Serialize + ?Sized>(pub &'a T);
+
+impl<T: Serialize + ?Sized> Render for ScriptJson<'_, T> {
+    fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        let json = serde_json::to_string(self.0).map_err(|e| RenderError::new(&e.to_string()))?;
+        b.push_str(&escape_script(&json));
+        Ok(())
+    }
+}
+
+/// Renders the value as JSON into a script element, with [`JsFunction`] values emitted as code
+pub struct JsonWithFunctions<'a, T: Serialize>(pub &'a T);
+
+impl<T: Serialize> Render for JsonWithFunctions<'_, T> {
+    fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        let json = serde_json::to_string(self.0)
+            .and_then(|json| substitute_js_functions(&json))
+            .map_err(|e| RenderError::new(&e.to_string()))?;
+        b.push_str(&escape_script(&json));
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+use sailfish::{RenderError, TemplateSimple};
+use serde::Serialize;
+use crate::axis_typing::AxisKindMarker;
+use crate::common::Size;
+use crate::options::EChartOptions;
+use crate::templates::ScriptTemplate;
+
+/// Chart with its axis types erased, so charts of different types can be put on one page
+trait ChartFragment {
+    /// Render the chart, in the given group if any
+    fn render_fragment(self: Box<Self>, group: Option<&str>) -> Result<String, RenderError>;
+}
+
+impl<X,Y> ChartFragment for ScriptTemplate<X,Y>
+where X: AxisKindMarker, Y: AxisKindMarker, EChartOptions<X,Y>: Serialize {
+    fn render_fragment(self: Box<Self>, group: Option<&str>) -> Result<String, RenderError> {
+        match group {
+            Some(group) => self.group(group).render_once(),
+            None => self.render_once(),
+        }
+    }
+}
+
+/// Charts linked with `echarts.connect`: tooltips, axis pointers, data zoom
+/// and legend selection are shared between them.
+/// Each chart joins the group when its script runs and the group is connected once per page,
+/// so the charts can be rendered into one page or arrive in separate htmx fragments
+#[derive(Debug, Clone)]
+pub struct ChartGroup {
+    id: String,
+}
+
+impl ChartGroup {
+
+    pub fn new(id: &str) -> Self {
+        Self { id: id.to_string() }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Put the chart into the group
+    pub fn add<X,Y>(&self, chart: ScriptTemplate<X,Y>) -> ScriptTemplate<X,Y>
+    where X: AxisKindMarker, Y: AxisKindMarker, EChartOptions<X,Y>: Serialize {
+        chart.group(&self.id)
+    }
+}
+
+/// One chart of a dashboard and its place in the grid
+pub struct Panel {
+    chart: Box<dyn ChartFragment>,
+    title: Option<String>,
+    /// Grid column and row the panel starts at, counted from 1, placed automatically when not set
+    position: Option<(usize, usize)>,
+    /// Number of columns and rows the panel covers
+    span: (usize, usize),
+}
+
+impl Panel {
+
+    /// Panel for a built chart, charts are best built with 100% width to fill the grid cell
+    pub fn new<X,Y>(chart: ScriptTemplate<X,Y>) -> Self
+    where X: AxisKindMarker + 'static, Y: AxisKindMarker + 'static, EChartOptions<X,Y>: Serialize {
+        Self {
+            chart: Box::new(chart),
+            title: None,
+            position: None,
+            span: (1, 1),
+        }
+    }
+
+    /// Heading shown above the chart
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Place the panel at the given grid column and row, counted from 1
+    pub fn at(mut self, column: usize, row: usize) -> Self {
+        self.position = Some((column, row));
+        self
+    }
+
+    /// Stretch the panel over several columns and rows
+    pub fn span(mut self, columns: usize, rows: usize) -> Self {
+        self.span = (columns.max(1), rows.max(1));
+        self
+    }
+
+    fn grid_placement(&self) -> String {
+        let (columns, rows) = self.span;
+        match self.position {
+            Some((column, row)) => format!("grid-column: {column} / span {columns}; grid-row: {row} / span {rows};"),
+            None => format!("grid-column: span {columns}; grid-row: span {rows};"),
+        }
+    }
+}
+
+/// Complete HTML page with several charts laid out in a CSS grid.
+/// ECharts and ecStat are loaded once in the page head
+#[derive(TemplateSimple)]
+#[template(path = "dashboard.stpl")]
+pub struct Dashboard {
+    title: String,
+    columns: usize,
+    gap: Size,
+    head_assets: Vec<String>,
+    panels: Vec<Panel>,
+    group: Option<String>,
+}
+
+impl Dashboard {
+
+    /// Dashboard with two columns and a 16px gap
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            columns: 2,
+            gap: Size::pixels(16),
+            head_assets: Vec::new(),
+            panels: Vec::new(),
+            group: None,
+        }
+    }
+
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Space between the panels and around the grid
+    pub fn gap(mut self, gap: Size) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Raw HTML added to the page head after the ECharts scripts,
+    /// e.g. a theme or locale script, a stylesheet or htmx
+    pub fn head_asset(mut self, html: &str) -> Self {
+        self.head_assets.push(html.to_string());
+        self
+    }
+
+    pub fn add_panel(mut self, panel: Panel) -> Self {
+        self.panels.push(panel);
+        self
+    }
+
+    /// Add a chart with a title, placed in the next free grid cell
+    pub fn add_chart<X,Y>(self, title: &str, chart: ScriptTemplate<X,Y>) -> Self
+    where X: AxisKindMarker + 'static, Y: AxisKindMarker + 'static, EChartOptions<X,Y>: Serialize {
+        self.add_panel(Panel::new(chart).title(title))
+    }
+
+    /// Link all charts of the dashboard in one [`ChartGroup`],
+    /// replacing the groups set on the charts themselves
+    pub fn connect(mut self) -> Self {
+        self.group = Some("dashboard".to_string());
+        self
+    }
+}
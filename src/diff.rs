@@ -0,0 +1,158 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+use crate::axis_typing::AxisKindMarker;
+use crate::options::EChartOptions;
+
+/// Top level option keys ECharts treats as component lists, merged element by element
+const COMPONENTS: &[&str] = &[
+    "title", "legend", "grid", "xAxis", "yAxis", "polar", "radiusAxis", "angleAxis", "radar",
+    "dataZoom", "visualMap", "tooltip", "axisPointer", "toolbox", "brush", "geo", "parallel",
+    "parallelAxis", "singleAxis", "timeline", "graphic", "calendar", "dataset", "aria", "series",
+];
+
+/// Difference between two option states, to be applied with
+/// `chart.setOption(option, {replaceMerge: replace_merge})`
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionPatch {
+    /// Partial option holding only what changed
+    pub option: Value,
+
+    /// Component types some components were removed from or reordered in. These are sent complete,
+    /// ECharts drops the existing components that are not in them
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub replace_merge: Vec<String>,
+}
+
+impl OptionPatch {
+
+    /// Nothing changed, no update has to be sent
+    pub fn is_empty(&self) -> bool {
+        self.option.as_object().is_none_or(Map::is_empty) && self.replace_merge.is_empty()
+    }
+}
+
+/// Compute the minimal patch turning the previous options into the current ones
+/// under the ECharts merge rules: objects are merged key by key, arrays inside them are replaced,
+/// and components are matched by `id`, then `name`, then position
+pub fn diff<X: AxisKindMarker, Y: AxisKindMarker>(previous: &EChartOptions<X,Y>, current: &EChartOptions<X,Y>) -> serde_json::Result<OptionPatch>
+where EChartOptions<X,Y>: Serialize {
+    Ok(diff_values(&serde_json::to_value(previous)?, &serde_json::to_value(current)?))
+}
+
+/// [`diff`] for options that are already serialized
+pub fn diff_values(previous: &Value, current: &Value) -> OptionPatch {
+    let empty = Map::new();
+    let previous = previous.as_object().unwrap_or(&empty);
+    let current = current.as_object().unwrap_or(&empty);
+    let mut option = Map::new();
+    let mut replace_merge = Vec::new();
+
+    for (key, new) in current {
+        let old = previous.get(key);
+        if COMPONENTS.contains(&key.as_str()) {
+            let old = old.map(as_list).unwrap_or_default();
+            let new = as_list(new);
+            let matches = match_components(&old, &new);
+            if needs_replace(&matches, old.len()) {
+                option.insert(key.clone(), Value::Array(new.into_iter().cloned().collect()));
+                replace_merge.push(key.clone());
+            } else if let Some(patch) = diff_components(&old, &new, &matches) {
+                option.insert(key.clone(), patch);
+            }
+        } else if let Some(patch) = diff_value(old, new) {
+            option.insert(key.clone(), patch);
+        }
+    }
+    for key in previous.keys().filter(|k| !current.contains_key(*k)) {
+        if COMPONENTS.contains(&key.as_str()) {
+            option.insert(key.clone(), Value::Array(Vec::new()));
+            replace_merge.push(key.clone());
+        } else {
+            option.insert(key.clone(), Value::Null);
+        }
+    }
+    OptionPatch { option: Value::Object(option), replace_merge }
+}
+
+/// Components may be given as a single object or as an array
+fn as_list(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(items) => items.iter().collect(),
+        Value::Null => Vec::new(),
+        other => vec![other],
+    }
+}
+
+/// Key the component is matched by, if it has one
+fn identity(component: &Value) -> Option<(&'static str, &Value)> {
+    ["id", "name"].into_iter().find_map(|key| component.get(key).map(|v| (key, v)))
+}
+
+/// Index of the previous component each current one is merged into, by id or name, else by position
+fn match_components(old: &[&Value], new: &[&Value]) -> Vec<Option<usize>> {
+    new.iter().enumerate().map(|(index, component)| match identity(component) {
+        Some((key, value)) => old.iter().position(|o| o.get(key) == Some(value)),
+        None => (index < old.len()).then_some(index),
+    }).collect()
+}
+
+/// A merge can't remove components or change their order, and it appends new ones at the end:
+/// when a previous component has no match, the matched ones are in a different order
+/// or a new one comes before a matched one, the whole list has to be replaced
+fn needs_replace(matches: &[Option<usize>], old_len: usize) -> bool {
+    let matched: Vec<usize> = matches.iter().flatten().copied().collect();
+    let inserted = matches.iter().skip_while(|m| m.is_some()).any(Option::is_some);
+    matched.len() < old_len || inserted || matched.windows(2).any(|pair| pair[0] >= pair[1])
+}
+
+/// Patch entries for every component up to the last changed one.
+/// Unchanged components get an entry with just their id or name, or an empty one,
+/// so that the following entries are matched with the right components
+fn diff_components(old: &[&Value], new: &[&Value], matches: &[Option<usize>]) -> Option<Value> {
+    let mut entries = Vec::new();
+    let mut last_changed = None;
+    for (index, component) in new.iter().enumerate() {
+        let matched = matches[index].map(|i| old[i]);
+        let patch = diff_value(matched, component);
+        let mut entry = match patch {
+            Some(Value::Object(map)) => map,
+            Some(other) => {
+                entries.push(other);
+                last_changed = Some(index);
+                continue;
+            }
+            None => Map::new(),
+        };
+        if !entry.is_empty() {
+            last_changed = Some(index);
+        }
+        if let Some((key, value)) = identity(component) {
+            entry.insert(key.to_string(), value.clone());
+        }
+        entries.push(Value::Object(entry));
+    }
+    let last_changed = last_changed?;
+    entries.truncate(last_changed + 1);
+    Some(Value::Array(entries))
+}
+
+/// Patch for a single value, `None` when it didn't change
+fn diff_value(old: Option<&Value>, new: &Value) -> Option<Value> {
+    match (old, new) {
+        (Some(old), new) if old == new => None,
+        (Some(Value::Object(old)), Value::Object(new)) => {
+            let mut patch = Map::new();
+            for (key, value) in new {
+                if let Some(changed) = diff_value(old.get(key), value) {
+                    patch.insert(key.clone(), changed);
+                }
+            }
+            for key in old.keys().filter(|k| !new.contains_key(*k)) {
+                patch.insert(key.clone(), Value::Null);
+            }
+            Some(Value::Object(patch))
+        }
+        (_, new) => Some(new.clone()),
+    }
+}
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::common::JsFunction;
+
+/// Chart events handlers can be registered for
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChartEvent {
+    Click,
+    DblClick,
+    MouseOver,
+    MouseOut,
+    ContextMenu,
+    /// A series was toggled in the legend, params hold `name` and `selected`
+    LegendSelectChanged,
+    /// The zoom window changed, params hold `start` and `end` or `batch`
+    DataZoom,
+    /// Any other ECharts event by its name
+    Other(String),
+}
+
+impl ChartEvent {
+    /// Name of the event in `chart.on`
+    pub fn name(&self) -> &str {
+        match self {
+            ChartEvent::Click => "click",
+            ChartEvent::DblClick => "dblclick",
+            ChartEvent::MouseOver => "mouseover",
+            ChartEvent::MouseOut => "mouseout",
+            ChartEvent::ContextMenu => "contextmenu",
+            ChartEvent::LegendSelectChanged => "legendselectchanged",
+            ChartEvent::DataZoom => "datazoom",
+            ChartEvent::Other(name) => name,
+        }
+    }
+}
+
+/// What happens when a chart event fires.
+/// Url templates refer to the event params in braces, e.g. `/orders/{name}` or `/series/{seriesName}/{dataIndex}`,
+/// the values are url-encoded, arrays are joined with commas
+#[derive(Debug, Clone)]
+pub enum EventAction {
+    /// JS function called with the event params, `this` is the chart instance
+    Function(JsFunction),
+    /// Navigate to the url
+    Navigate(String),
+    /// GET the url with htmx and swap the response into the target element.
+    /// The series name, data name, data index and value of the event are sent as query parameters
+    Htmx {
+        url: String,
+        /// CSS selector of the element receiving the response
+        target: String,
+        /// htmx swap style, innerHTML by default
+        swap: Option<String>,
+    },
+}
+
+impl EventAction {
+
+    pub fn navigate(url_template: &str) -> Self {
+        EventAction::Navigate(url_template.to_string())
+    }
+
+    pub fn htmx_get(url_template: &str, target: &str) -> Self {
+        EventAction::Htmx { url: url_template.to_string(), target: target.to_string(), swap: None }
+    }
+}
+
+/// Handler registered with `chart.on` in the generated script
+#[derive(Debug, Clone)]
+pub struct EventHandler {
+    pub event: ChartEvent,
+    pub action: EventAction,
+}
+
+/// Where the `brushSelected` event of the chart is forwarded to.
+/// The forwarded payload is the JSON form of [`BrushSelected`]
+#[derive(Debug, Clone)]
+pub enum BrushSelectedHandler {
+    /// POST the selection as JSON to the url.
+    /// When a target element id is given, the response HTML replaces its content
+    /// and is processed by htmx if it is loaded on the page
+    Post { url: String, target: Option<String> },
+    /// Call a global JS function with the selection and the chart instance
+    Callback(String),
+    /// Dispatch an `echarts:brushselected` DOM event on the chart div, with the selection as detail.
+    /// It bubbles, so htmx can pick it up with `hx-trigger="echarts:brushselected from:#chart-id"`
+    DomEvent,
+}
+
+impl BrushSelectedHandler {
+
+    pub fn post(url: &str) -> Self {
+        BrushSelectedHandler::Post { url: url.to_string(), target: None }
+    }
+
+    /// POST the selection and put the response into the element with the target id
+    pub fn post_into(url: &str, target_id: &str) -> Self {
+        BrushSelectedHandler::Post { url: url.to_string(), target: Some(target_id.to_string()) }
+    }
+
+    pub fn callback(function_name: &str) -> Self {
+        BrushSelectedHandler::Callback(function_name.to_string())
+    }
+}
+
+/// Area drawn by the brush
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrushArea {
+    pub brush_type: String,
+
+    /// Range in pixels
+    #[serde(default)]
+    pub range: Value,
+
+    /// Range in axis values, timestamps in milliseconds for time axes
+    #[serde(default)]
+    pub coord_range: Value,
+}
+
+/// Data of one series inside the selection
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrushSeriesSelection {
+    pub series_index: usize,
+
+    #[serde(default)]
+    pub series_name: Option<String>,
+
+    /// Indexes of the selected data points in the series data
+    pub data_index: Vec<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrushBatch {
+    #[serde(default)]
+    pub brush_id: Option<String>,
+
+    pub areas: Vec<BrushArea>,
+
+    pub selected: Vec<BrushSeriesSelection>,
+}
+
+/// Payload of the `brushSelected` event, as forwarded by [`BrushSelectedHandler`].
+/// Can be used to deserialize the request body on the server
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrushSelected {
+    pub batch: Vec<BrushBatch>,
+}
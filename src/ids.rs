@@ -0,0 +1,79 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use serde::Serialize;
+use crate::common::{substitute_js_functions, Size};
+
+/// FNV-1a hash, stable across builds and Rust releases unlike the std hashers
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Container id derived from the chart content: hash of the serialized options and the size,
+/// so the same chart gets the same id across renders and builds.
+/// [`crate::common::JsFunction`] values are hashed as their code, their tag differs per process
+pub(crate) fn content_id<T: Serialize + ?Sized>(content: &T, width: &Size, height: &Size) -> String {
+    let json = serde_json::to_string(content)
+        .and_then(|json| substitute_js_functions(&json))
+        .unwrap_or_default();
+    let mut bytes = json.into_bytes();
+    bytes.extend(format!("|{width}|{height}").into_bytes());
+    format!("chart-{:016x}", fnv1a(&bytes))
+}
+
+/// Counter handing out sequential container ids, `{prefix}-{n}` starting at the seed.
+/// Create one per rendered page and pass it by reference to the charts of that page:
+/// `let ids = IdCounter::seeded("chart", 1); chart.build_with_id_strategy(IdStrategy::Counter(&ids), w, h)`.
+/// Don't share one in a `static` on a server, concurrent requests would interleave their ids
+/// and `reset` of one request would hand out ids already used by another
+#[derive(Debug)]
+pub struct IdCounter {
+    prefix: &'static str,
+    next: AtomicU64,
+}
+
+impl IdCounter {
+
+    pub const fn seeded(prefix: &'static str, seed: u64) -> Self {
+        Self { prefix, next: AtomicU64::new(seed) }
+    }
+
+    pub fn next_id(&self) -> String {
+        format!("{}-{}", self.prefix, self.next.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Start over from the seed, e.g. when a test renders a page again
+    pub fn reset(&self, seed: u64) {
+        self.next.store(seed, Ordering::Relaxed);
+    }
+}
+
+/// Counter of the default ids, never reset, so no id is handed out twice by the process
+static DEFAULT_IDS: IdCounter = IdCounter::seeded("echarts", 1);
+
+/// How the id of the chart container div is chosen when a chart is built
+#[derive(Debug, Clone, Copy)]
+pub enum IdStrategy<'a> {
+    /// Next id of a process wide counter, `echarts-{n}`, the default of `build`.
+    /// Deterministic for a given rendering order and never repeated, so unique on any page
+    Unique,
+    /// Derived from the options and size, so the same chart renders the same markup, e.g. for snapshots.
+    /// Identical charts get the same id, so they can't be shown on the same page
+    ContentHash,
+    /// Next id of the counter, unique within a page but dependent on the rendering order
+    Counter(&'a IdCounter),
+    /// Id supplied by the caller
+    Fixed(&'a str),
+}
+
+impl IdStrategy<'_> {
+
+    pub(crate) fn id<T: Serialize + ?Sized>(&self, content: &T, width: &Size, height: &Size) -> String {
+        match self {
+            IdStrategy::Unique => DEFAULT_IDS.next_id(),
+            IdStrategy::ContentHash => content_id(content, width, height),
+            IdStrategy::Counter(counter) => counter.next_id(),
+            IdStrategy::Fixed(id) => id.to_string(),
+        }
+    }
+}
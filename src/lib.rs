@@ -3,6 +3,15 @@ mod builder;
 mod macros;
 pub mod templates;
 pub mod common;
+pub mod presets;
+pub mod theme;
+pub mod events;
+pub mod timeline;
+pub mod dashboard;
+pub mod live;
+pub mod diff;
+pub mod ids;
+mod accessibility;
 
 mod axis_typing;
 
@@ -13,6 +22,27 @@ pub mod time_axis;
 #[cfg(feature = "chrono_axis")]
 pub mod chrono_axis;
 
+#[cfg(feature = "axum")]
+mod axum_integration;
+
+#[cfg(feature = "actix")]
+pub mod actix_integration;
+
+#[cfg(feature = "askama")]
+mod askama_integration;
+
+#[cfg(feature = "maud")]
+mod maud_integration;
+
+#[cfg(feature = "minijinja")]
+pub mod minijinja_integration;
+
+#[cfg(feature = "tera")]
+pub mod tera_integration;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
 pub use axis_typing::{AxisKindMarker,TimeAxis,CategoryAxis,ValueAxis};
 pub use options::EChartOptions;
 
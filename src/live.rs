@@ -0,0 +1,89 @@
+use serde::Serialize;
+use serde_json::Value;
+use crate::axis_typing::{AxisKindMarker, ValueSerializeWrapper};
+use crate::diff::OptionPatch;
+
+/// Where a chart receives live updates from. Each message is the JSON form of a [`LiveUpdate`]
+#[derive(Debug, Clone)]
+pub enum LiveSource {
+    /// Server-Sent Events url, the message is the `data` of each event
+    Sse(String),
+    /// WebSocket url, absolute or relative to the page. Reconnects a second after the connection drops
+    WebSocket(String),
+}
+
+impl LiveSource {
+
+    pub fn sse(url: &str) -> Self {
+        LiveSource::Sse(url.to_string())
+    }
+
+    pub fn websocket(url: &str) -> Self {
+        LiveSource::WebSocket(url.to_string())
+    }
+}
+
+/// Update message applied to a live chart.
+/// Series are referred to by name, so they have to be named and hold their data inline
+#[derive(Serialize, Debug, Clone)]
+#[serde(bound = "")]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum LiveUpdate<X: AxisKindMarker, Y: AxisKindMarker> {
+    /// Partial option merged with `setOption`, e.g. an [`OptionPatch`]
+    Merge {
+        option: Value,
+        /// Component types whose components missing from the option are removed
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        replace_merge: Vec<String>,
+    },
+    /// Add points to the end of the series
+    Append { series: String, data: Vec<(ValueSerializeWrapper<X>, ValueSerializeWrapper<Y>)> },
+    /// Add points to the end of the series and drop as many from the start, moving the visible window
+    Shift { series: String, data: Vec<(ValueSerializeWrapper<X>, ValueSerializeWrapper<Y>)> },
+    /// Add points with `appendData`, for large scatter-like series rendered progressively
+    AppendData { series: String, data: Vec<(ValueSerializeWrapper<X>, ValueSerializeWrapper<Y>)> },
+    /// Replace the source of the dataset at the index
+    ReplaceDataset { index: usize, source: Vec<(ValueSerializeWrapper<X>, ValueSerializeWrapper<Y>)> },
+}
+
+fn points<X: AxisKindMarker, Y: AxisKindMarker>(points: Vec<(X, Y)>) -> Vec<(ValueSerializeWrapper<X>, ValueSerializeWrapper<Y>)> {
+    points.into_iter().map(|(x, y)| (x.into(), y.into())).collect()
+}
+
+impl<X: AxisKindMarker, Y: AxisKindMarker> LiveUpdate<X,Y> {
+
+    pub fn merge<T: Serialize>(option: &T) -> serde_json::Result<Self> {
+        Ok(LiveUpdate::Merge { option: serde_json::to_value(option)?, replace_merge: Vec::new() })
+    }
+
+    /// Send the difference between two option states computed by [`crate::diff::diff`]
+    pub fn patch(patch: OptionPatch) -> Self {
+        LiveUpdate::Merge { option: patch.option, replace_merge: patch.replace_merge }
+    }
+
+    pub fn append(series: &str, data: Vec<(X, Y)>) -> Self {
+        LiveUpdate::Append { series: series.to_string(), data: points(data) }
+    }
+
+    pub fn shift(series: &str, data: Vec<(X, Y)>) -> Self {
+        LiveUpdate::Shift { series: series.to_string(), data: points(data) }
+    }
+
+    pub fn append_data(series: &str, data: Vec<(X, Y)>) -> Self {
+        LiveUpdate::AppendData { series: series.to_string(), data: points(data) }
+    }
+
+    pub fn replace_dataset(index: usize, source: Vec<(X, Y)>) -> Self {
+        LiveUpdate::ReplaceDataset { index, source: points(source) }
+    }
+
+    /// Message to send over a WebSocket
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Complete Server-Sent Event carrying the message
+    pub fn to_sse_event(&self) -> serde_json::Result<String> {
+        Ok(format!("data: {}\n\n", self.to_json()?))
+    }
+}
@@ -0,0 +1,21 @@
+use maud::Render;
+use sailfish::TemplateSimple;
+use serde::Serialize;
+use crate::axis_typing::AxisKindMarker;
+use crate::options::EChartOptions;
+use crate::templates::ScriptTemplate;
+
+/// Embeds the chart fragment unescaped, e.g. `html! { main { (chart) } }`.
+/// `Render` can't fail, a rendering error is written as an HTML comment in place of the chart
+impl<X,Y> Render for ScriptTemplate<X,Y>
+where X: AxisKindMarker + Clone, Y: AxisKindMarker + Clone, EChartOptions<X,Y>: Serialize {
+    fn render_to(&self, buffer: &mut String) {
+        match self.clone().render_once() {
+            Ok(html) => buffer.push_str(&html),
+            Err(error) => {
+                let error = error.to_string().replace("--", "- -");
+                buffer.push_str(&format!("<!-- failed to render chart: {error} -->"));
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use minijinja::value::Kwargs;
+use minijinja::{Environment, Error, ErrorKind, Value};
+use sailfish::TemplateSimple;
+use crate::common::Size;
+use crate::ids::IdStrategy;
+use crate::templates::ScriptTemplate;
+
+fn size(kwargs: &Kwargs, name: &str, default: &str) -> Result<Size, Error> {
+    let value: Option<Value> = kwargs.get(name)?;
+    let text = value.map(|v| v.to_string()).unwrap_or_else(|| default.to_string());
+    text.parse().map_err(|e: String| Error::new(ErrorKind::InvalidOperation, e))
+}
+
+/// Filter rendering serialized chart options as the chart fragment:
+/// `{{ options | echarts_chart(width="100%", height="400px", id="sales") }}`.
+/// All arguments are optional, the size defaults to 100% x 400px and the id to the next `echarts-{n}`
+pub fn echarts_chart(options: Value, kwargs: Kwargs) -> Result<Value, Error> {
+    let width = size(&kwargs, "width", "100%")?;
+    let height = size(&kwargs, "height", "400px")?;
+    let id: Option<String> = kwargs.get("id")?;
+    kwargs.assert_all_used()?;
+    let options = serde_json::to_value(&options)
+        .map_err(|e| Error::new(ErrorKind::InvalidOperation, "chart options are not serializable").with_source(e))?;
+    let html = ScriptTemplate::<f64, f64>::from_json(id.unwrap_or_else(|| IdStrategy::Unique.id(&options, &width, &height)), width, height, options)
+        .render_once()
+        .map_err(|e| Error::new(ErrorKind::InvalidOperation, "failed to render chart").with_source(e))?;
+    Ok(Value::from_safe_string(html))
+}
+
+/// Register the `echarts_chart` filter
+pub fn register(env: &mut Environment) {
+    env.add_filter("echarts_chart", echarts_chart);
+}
@@ -38,6 +38,38 @@ pub struct EChartOptions<X:AxisKindMarker,Y:AxisKindMarker> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) series: Option<Vec<Series<X,Y>>>,
 
+    /// Color palette, series pick colors from it in order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Vec<Value>>,
+
+    /// Background color of the whole chart
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<Value>,
+
+    /// Global text style, used when a component does not override it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_style: Option<TextStyle>,
+
+    /// Zoom and pan components bound to the axes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_zoom: Option<Vec<AxisDataZoom<X,Y>>>,
+
+    /// Mappings from data values to colors, sizes and opacity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visual_map: Option<Vec<VisualMap>>,
+
+    /// Toolbox with export, data view and chart type switching
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub toolbox: Option<Toolbox>,
+
+    /// Area selection of data points
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brush: Option<Brush>,
+
+    /// Accessibility: generated or custom descriptions and decal patterns
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aria: Option<Aria>,
+
     /// Additional raw options not covered by this binding
     #[serde(flatten)]
     pub extra: Option<Value>,
@@ -73,6 +105,10 @@ pub enum Position {
 #[derive(Serialize, Deserialize, Debug, Clone,Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Title {
+    /// Component id, used by ECharts to match components when merging `setOption` calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
     /// Main title text
     pub text: Option<String>,
 
@@ -108,6 +144,7 @@ pub struct Title {
 impl Title {
     pub fn new(text: &str) -> Title {
         Self{
+            id: None,
             text: Some(text.to_string()),
             sub_text: None,
             link: None,
@@ -118,12 +155,21 @@ impl Title {
             extra: None,
         }
     }
+
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
 }
 
 /// Grid component
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Grid {
+    /// Component id, used by ECharts to match components when merging `setOption` calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
     /// Distance between grid and left side (Keyword, numeric px, percent, or other)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub left: Option<Position>,
@@ -173,6 +219,13 @@ pub struct Grid {
     pub extra: Option<Value>,
 }
 
+impl Grid {
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub enum TooltipTrigger {
@@ -242,6 +295,48 @@ pub struct Tooltip {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub axis_pointer: Option<AxisPointer>,
 
+    /// Background color of the tooltip box
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<Value>,
+
+    /// Border color of the tooltip box
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border_color: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border_width: Option<f64>,
+
+    /// Inner padding in px, either one value or [top, right, bottom, left]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub padding: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_style: Option<TextStyle>,
+
+}
+
+/// Text style shared by components (global textStyle, tooltip text, etc.)
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TextStyle {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_style: Option<FontStyle>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_weight: Option<FontWeight>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_family: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<f64>,
+
+    /// Additional raw text style options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -252,9 +347,13 @@ pub enum LegendOrient {
 }
 
 /// Legend component
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Legend {
+    /// Component id, used by ECharts to match components when merging `setOption` calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
     /// Data items in the legend
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<Vec<String>>,
@@ -277,345 +376,1860 @@ pub struct Legend {
     pub bottom: Option<Position>
 }
 
-impl Default for Legend {
-    fn default() -> Self {
-        Self{
-            data: None,
-            orient: None,
-            left: None,
-            right:  None,
-            top:  None,
-            bottom: None,
-        }
+impl Legend {
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum DataZoomType {
+    /// Slider bar displayed next to the grid
+    Slider,
+    /// Zoom with the mouse wheel and pan by dragging inside the grid
+    Inside
+}
 
-/// Axis types supported by ECharts
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
-#[serde(rename_all = "lowercase")]
-pub enum AxisType {
-    Value,
-    Category,
-    Time,
-    Log
+/// How the data outside the zoomed window is treated
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum DataZoomFilterMode {
+    /// Data outside the window is filtered out, other axes adjust to the visible data
+    Filter,
+    /// Like filter, but keeps the points whose other dimensions are in range
+    WeakFilter,
+    /// Data outside the window is set to empty
+    Empty,
+    /// Data is not filtered
+    None
 }
 
+/// dataZoom component, zooming a window of one of the axes.
+/// The window can be expressed in percent of the data range (start, end)
+/// or in values of the axis (start_value, end_value)
 #[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct  NamedValuePair<X:AxisKindMarker,Y:AxisKindMarker>{
-    value: (ValueSerializeWrapper<X>,ValueSerializeWrapper<Y>),
-    name: String,
+pub struct DataZoom<T:AxisKindMarker> {
+    /// Component id, used by ECharts to match components when merging `setOption` calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    pub r#type: DataZoomType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x_axis_index: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub y_axis_index: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_mode: Option<DataZoomFilterMode>,
+
+    /// Start of the window in percent, 0 to 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<f64>,
+
+    /// End of the window in percent, 0 to 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<f64>,
+
+    /// Start of the window as an axis value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_value: Option<ValueSerializeWrapper<T>>,
+
+    /// End of the window as an axis value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_value: Option<ValueSerializeWrapper<T>>,
+
+    /// Minimum window size in percent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_span: Option<f64>,
+
+    /// Maximum window size in percent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_span: Option<f64>,
+
+    /// Keep the window size fixed, only panning is allowed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zoom_lock: Option<bool>,
+
+    /// Redraw while dragging instead of when the drag ends
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub realtime: Option<bool>,
+
+    /// Slider only, orientation of the slider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orient: Option<LegendOrient>,
+
+    /// Slider only, position of the slider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left: Option<Position>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub right: Option<Position>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top: Option<Position>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bottom: Option<Position>,
+
+    /// Additional raw dataZoom options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
 }
 
-impl<X:AxisKindMarker,Y:AxisKindMarker> NamedValuePair<X,Y> {
-    pub fn new(x: X, y: Y, name: String) -> Self {
+impl<T:AxisKindMarker> DataZoom<T> {
+
+    pub fn new(r#type: DataZoomType) -> Self {
         Self{
-            value: (x.into(), y.into()),
-            name,
+            id: None,
+            r#type,
+            x_axis_index: None,
+            y_axis_index: None,
+            filter_mode: None,
+            start: None,
+            end: None,
+            start_value: None,
+            end_value: None,
+            min_span: None,
+            max_span: None,
+            zoom_lock: None,
+            realtime: None,
+            orient: None,
+            left: None,
+            right: None,
+            top: None,
+            bottom: None,
+            extra: None,
         }
     }
 
+    pub fn slider() -> Self {
+        Self::new(DataZoomType::Slider)
+    }
+
+    pub fn inside() -> Self {
+        Self::new(DataZoomType::Inside)
+    }
+
+    /// Window in percent of the data range
+    pub fn range_percent(mut self, start: f64, end: f64) -> Self {
+        self.start = Some(start);
+        self.end = Some(end);
+        self
+    }
+
+    /// Window in axis values
+    pub fn range_values(mut self, start: T, end: T) -> Self {
+        self.start_value = Some(start.into());
+        self.end_value = Some(end.into());
+        self
+    }
+
+    pub fn filter_mode(mut self, filter_mode: DataZoomFilterMode) -> Self {
+        self.filter_mode = Some(filter_mode);
+        self
+    }
+
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
 }
 
+/// dataZoom bound to either the x or the y axis of the chart
 #[derive(Serialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct  NamedValue<X:AxisKindMarker>{
-    value: ValueSerializeWrapper<X>,
-    name: String,
+#[serde(untagged)]
+pub enum AxisDataZoom<X:AxisKindMarker,Y:AxisKindMarker> {
+    X(DataZoom<X>),
+    Y(DataZoom<Y>)
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum VisualMapType {
+    /// Values are mapped onto a gradient between min and max
+    Continuous,
+    /// Values are split into pieces, each with its own visual
+    Piecewise
+}
 
-/// Axis (cartesian)
-#[derive(Serialize, Debug, Clone)]
+/// Visual channels applied to the values in or out of the visualMap range.
+/// Each channel is a list the values are mapped onto, e.g. colors from low to high
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct Axis<T:AxisKindMarker> {
-    /// Axis type: value, category, time, log
-    pub(crate) r#type: AxisType,
+pub struct VisualChannels {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Vec<Value>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) inverse: Option<bool>,
+    pub symbol_size: Option<Vec<f64>>,
 
-    /// Axis name
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) name: Option<String>,
+    pub opacity: Option<Vec<f64>>,
 
-    /// Data for category axis
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data: Option<Vec<ValueSerializeWrapper<T>>>,
+    pub color_alpha: Option<Vec<f64>>,
 
-    /// Additional raw axis options
+    /// Additional raw visual channels
     #[serde(flatten)]
     pub extra: Option<Value>,
 }
 
-impl<T:AxisKindMarker> Default for Axis<T> {
-    fn default() -> Self {
+impl VisualChannels {
+    pub fn colors(colors: &[&str]) -> Self {
         Self{
-            r#type: T::AxisType::AXIS_TYPE,
-            inverse: None,
-            name: None,
-            data: None,
-            extra: None,
+            color: Some(colors.iter().map(|c| Value::from(*c)).collect()),
+            ..Default::default()
         }
     }
 }
 
+/// Piece of a piecewise visualMap, either an interval or an exact value
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VisualPiece {
+    /// Inclusive lower bound
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+
+    /// Inclusive upper bound
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
 
-impl<T:AxisKindMarker> Axis<T>{
+    /// Exclusive lower bound
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gt: Option<f64>,
 
-    pub fn new_named(name: String)-> Self{
+    /// Exclusive upper bound
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lt: Option<f64>,
+
+    /// Exact value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opacity: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<DataPointSymbol>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol_size: Option<f64>,
+}
+
+impl VisualPiece {
+
+    /// Piece from min to max, both inclusive. Leave a bound out for an open interval
+    pub fn range(min: Option<f64>, max: Option<f64>) -> Self {
         Self{
-            r#type:  T::AxisType::AXIS_TYPE,
-            name: Some(name),
-            inverse: None,
-            data: None,
-            extra: None
+            min,
+            max,
+            ..Default::default()
         }
     }
 
-    pub fn new(name: String, is_log:bool, inverse: bool)-> Self{
-        if T::AxisType::AXIS_TYPE == AxisType::Value && is_log {
-            Self{
-                r#type:  AxisType::Log,
-                name: Some(name),
-                inverse: Some(inverse),
-                data: None,
-                extra: None,
-            }
-        }else {
-            Self{
-                r#type:  T::AxisType::AXIS_TYPE,
-                name: Some(name.to_string()),
-                inverse: Some(inverse),
-                data: None,
-                extra: None,
-            }
+    pub fn value(value: f64) -> Self {
+        Self{
+            value: Some(value),
+            ..Default::default()
         }
     }
-}
 
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
 
-/// Available series types in ECharts
+    pub fn color(mut self, color: &str) -> Self {
+        self.color = Some(Value::from(color));
+        self
+    }
+}
+
+/// visualMap component, mapping data values onto visual channels
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub enum SeriesType {
-    Line,
-    Bar,
-    Pie,
-    Scatter,
-    EffectScatter,
-    Radar,
-    Tree,
-    Treemap,
-    Sunburst,
-    Boxplot,
-    Candlestick,
-    Heatmap,
-    Map,
-    Parallel,
-    Lines,
-    Graph,
-    Sankey,
-    Funnel,
-    Gauge,
-    PictorialBar,
-    ThemeRiver,
-    Custom
-}
+pub struct VisualMap {
+    /// Component id, used by ECharts to match components when merging `setOption` calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
 
+    pub r#type: VisualMapType,
 
-#[derive(Serialize,  Debug, Clone)]
-#[serde(untagged)]
-pub enum DataVariant<X:AxisKindMarker,Y:AxisKindMarker>{
-    /// Single dimension data (string/int/float)
+    /// Lower bound of the mapped values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+
+    /// Upper bound of the mapped values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+
+    /// Continuous only, initially selected range
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<(f64, f64)>,
+
+    /// Continuous only, show handles to change the selected range
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calculable: Option<bool>,
+
+    /// Piecewise only, explicit pieces
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pieces: Option<Vec<VisualPiece>>,
+
+    /// Piecewise only, category values mapped one to one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<String>>,
+
+    /// Piecewise only, number of equal pieces between min and max when no pieces are given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_number: Option<usize>,
+
+    /// Data dimension the values are taken from, the last one by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimension: Option<usize>,
+
+    /// Series the map applies to, all series by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub series_index: Option<Vec<usize>>,
+
+    /// Visuals of the values in the selected range
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_range: Option<VisualChannels>,
+
+    /// Visuals of the values out of the selected range
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub out_of_range: Option<VisualChannels>,
+
+    /// Labels at the high and low ends
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<(String, String)>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orient: Option<LegendOrient>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left: Option<Position>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub right: Option<Position>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top: Option<Position>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bottom: Option<Position>,
+
+    /// Additional raw visualMap options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
+}
+
+impl VisualMap {
+
+    pub fn new(r#type: VisualMapType) -> Self {
+        Self{
+            id: None,
+            r#type,
+            min: None,
+            max: None,
+            range: None,
+            calculable: None,
+            pieces: None,
+            categories: None,
+            split_number: None,
+            dimension: None,
+            series_index: None,
+            in_range: None,
+            out_of_range: None,
+            text: None,
+            show: None,
+            orient: None,
+            left: None,
+            right: None,
+            top: None,
+            bottom: None,
+            extra: None,
+        }
+    }
+
+    /// Gradient between min and max
+    pub fn continuous(min: f64, max: f64) -> Self {
+        Self{
+            min: Some(min),
+            max: Some(max),
+            calculable: Some(true),
+            ..Self::new(VisualMapType::Continuous)
+        }
+    }
+
+    /// Explicit pieces, each with its own visual
+    pub fn piecewise(pieces: Vec<VisualPiece>) -> Self {
+        Self{
+            pieces: Some(pieces),
+            ..Self::new(VisualMapType::Piecewise)
+        }
+    }
+
+    pub fn in_range(mut self, in_range: VisualChannels) -> Self {
+        self.in_range = Some(in_range);
+        self
+    }
+
+    pub fn out_of_range(mut self, out_of_range: VisualChannels) -> Self {
+        self.out_of_range = Some(out_of_range);
+        self
+    }
+
+    pub fn dimension(mut self, dimension: usize) -> Self {
+        self.dimension = Some(dimension);
+        self
+    }
+
+    pub fn series_index(mut self, series_index: Vec<usize>) -> Self {
+        self.series_index = Some(series_index);
+        self
+    }
+
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum ImageType {
+    Png,
+    Jpg,
+    /// Only available with the svg renderer
+    Svg
+}
+
+/// Download the chart as an image
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveAsImageFeature {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<ImageType>,
+
+    /// File name without extension, the chart title by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Resolution multiplier of the image
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pixel_ratio: Option<f64>,
+
+    /// Background of the image, the chart background by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<Value>,
+
+    /// Components hidden in the image, e.g. ["toolbox"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_components: Option<Vec<String>>,
+}
+
+/// Show the chart data as a text table
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DataViewFeature {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// When false, edited data is applied to the chart
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+
+    /// Titles of the view, close and refresh buttons
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<(String, String, String)>,
+}
+
+/// Zoom by selecting an area of the grid
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DataZoomFeature {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Value>,
+
+    /// Reject the y-axis ("none") or x-axis index from zooming
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub y_axis_index: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x_axis_index: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_mode: Option<DataZoomFilterMode>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum MagicType {
+    Line,
+    Bar,
+    Stack
+}
+
+/// Switch the series between chart types
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MagicTypeFeature {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Vec<MagicType>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Value>,
+}
+
+/// Restore the initial option
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreFeature {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum BrushToolType {
+    Rect,
+    Polygon,
+    #[serde(rename = "lineX")]
+    LineX,
+    #[serde(rename = "lineY")]
+    LineY,
+    /// Keep previous selections when starting a new one
+    Keep,
+    /// Clear all selections
+    Clear
+}
+
+/// Buttons of the brush selection tools
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BrushFeature {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Vec<BrushToolType>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Value>,
+}
+
+/// Tools available in the toolbox, only the set ones are shown
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolboxFeatures {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub save_as_image: Option<SaveAsImageFeature>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_view: Option<DataViewFeature>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_zoom: Option<DataZoomFeature>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub magic_type: Option<MagicTypeFeature>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restore: Option<RestoreFeature>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brush: Option<BrushFeature>,
+}
+
+/// Toolbox component
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Toolbox {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show: Option<bool>,
+
+    pub feature: ToolboxFeatures,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orient: Option<LegendOrient>,
+
+    /// Size of the icons in px
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_size: Option<f64>,
+
+    /// Gap between the icons in px
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_gap: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left: Option<Position>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub right: Option<Position>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top: Option<Position>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bottom: Option<Position>,
+
+    /// Additional raw toolbox options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum BrushLinkKeyword {
+    All,
+    None
+}
+
+/// Series whose selections are linked, data with the same index is selected in all of them
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum BrushLink {
+    Keyword(BrushLinkKeyword),
+    Series(Vec<usize>)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum BrushMode {
+    Single,
+    Multiple
+}
+
+/// How often brushSelected is fired while the selection is being drawn
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum BrushThrottleType {
+    /// Fire once the selection stopped changing for throttle_delay
+    Debounce,
+    /// Fire every throttle_delay
+    FixRate
+}
+
+/// Brush component, selecting data points by drawing areas over the grid
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Brush {
+    /// Buttons added to the toolbox
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub toolbox: Option<Vec<BrushToolType>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brush_link: Option<BrushLink>,
+
+    /// Series the selection applies to, all series by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub series_index: Option<Vec<usize>>,
+
+    /// Brush type used when the selection is started without the toolbox
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brush_type: Option<BrushToolType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brush_mode: Option<BrushMode>,
+
+    /// Whether selected areas can be moved and resized
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transformable: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brush_style: Option<ItemStyle>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub throttle_type: Option<BrushThrottleType>,
+
+    /// Delay in milliseconds, 0 fires on every change
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub throttle_delay: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_on_click: Option<bool>,
+
+    /// Visuals of the selected data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_brush: Option<VisualChannels>,
+
+    /// Visuals of the data out of the selection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub out_of_brush: Option<VisualChannels>,
+
+    /// Additional raw brush options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
+}
+
+impl Brush {
+    /// Brush with the given toolbox buttons, linked across all series
+    pub fn new(toolbox: Vec<BrushToolType>) -> Self {
+        Self{
+            toolbox: Some(toolbox),
+            brush_link: Some(BrushLink::Keyword(BrushLinkKeyword::All)),
+            ..Default::default()
+        }
+    }
+}
+
+/// Description of the chart read by screen readers
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AriaLabel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Replaces the description ECharts generates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Additional raw label options (general, series, data templates)
+    #[serde(flatten)]
+    pub extra: Option<Value>,
+}
+
+/// Patterns drawn over series colors, so series can be told apart without colors
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AriaDecal {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show: Option<bool>,
+
+    /// Custom decal patterns, ECharts defaults are used when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decals: Option<Vec<Value>>,
+}
+
+/// Aria component
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Aria {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<AriaLabel>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decal: Option<AriaDecal>,
+
+    /// Additional raw aria options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
+}
+
+/// Axis types supported by ECharts
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AxisType {
+    Value,
+    Category,
+    Time,
+    Log
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(bound = "")]
+#[serde(rename_all = "camelCase")]
+pub struct  NamedValuePair<X:AxisKindMarker,Y:AxisKindMarker>{
+    value: (ValueSerializeWrapper<X>,ValueSerializeWrapper<Y>),
+    name: String,
+
+    /// Style overriding the series item style for this data point
+    #[serde(skip_serializing_if = "Option::is_none")]
+    item_style: Option<ItemStyle>,
+
+    /// Label overriding the series label for this data point
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<Label>,
+
+    /// Emphasis state overriding the series emphasis for this data point
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emphasis: Option<Emphasis>,
+}
+
+impl<X:AxisKindMarker,Y:AxisKindMarker> NamedValuePair<X,Y> {
+    pub fn new(x: X, y: Y, name: String) -> Self {
+        Self{
+            value: (x.into(), y.into()),
+            name,
+            item_style: None,
+            label: None,
+            emphasis: None,
+        }
+    }
+
+    pub fn item_style(mut self, item_style: ItemStyle) -> Self {
+        self.item_style = Some(item_style);
+        self
+    }
+
+    pub fn label(mut self, label: Label) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn emphasis(mut self, emphasis: Emphasis) -> Self {
+        self.emphasis = Some(emphasis);
+        self
+    }
+
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(bound = "")]
+#[serde(rename_all = "camelCase")]
+pub struct  NamedValue<X:AxisKindMarker>{
+    value: ValueSerializeWrapper<X>,
+    name: String,
+
+    /// Style overriding the series item style for this data point
+    #[serde(skip_serializing_if = "Option::is_none")]
+    item_style: Option<ItemStyle>,
+
+    /// Label overriding the series label for this data point
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<Label>,
+
+    /// Emphasis state overriding the series emphasis for this data point
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emphasis: Option<Emphasis>,
+}
+
+impl<X:AxisKindMarker> NamedValue<X> {
+    pub fn new(x: X, name: String) -> Self {
+        Self{
+            value: x.into(),
+            name,
+            item_style: None,
+            label: None,
+            emphasis: None,
+        }
+    }
+
+    pub fn item_style(mut self, item_style: ItemStyle) -> Self {
+        self.item_style = Some(item_style);
+        self
+    }
+
+    pub fn label(mut self, label: Label) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn emphasis(mut self, emphasis: Emphasis) -> Self {
+        self.emphasis = Some(emphasis);
+        self
+    }
+}
+
+
+/// Axis (cartesian)
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Axis<T:AxisKindMarker> {
+    /// Component id, used by ECharts to match components when merging `setOption` calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Axis type: value, category, time, log
+    pub(crate) r#type: AxisType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) inverse: Option<bool>,
+
+    /// Axis name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    /// Data for category axis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<ValueSerializeWrapper<T>>>,
+
+    /// Additional raw axis options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
+}
+
+impl<T:AxisKindMarker> Default for Axis<T> {
+    fn default() -> Self {
+        Self{
+            id: None,
+            r#type: T::AxisType::AXIS_TYPE,
+            inverse: None,
+            name: None,
+            data: None,
+            extra: None,
+        }
+    }
+}
+
+
+impl<T:AxisKindMarker> Axis<T>{
+
+    pub fn new_named(name: String)-> Self{
+        Self{
+            id: None,
+            r#type:  T::AxisType::AXIS_TYPE,
+            name: Some(name),
+            inverse: None,
+            data: None,
+            extra: None
+        }
+    }
+
+    pub fn new(name: String, is_log:bool, inverse: bool)-> Self{
+        if T::AxisType::AXIS_TYPE == AxisType::Value && is_log {
+            Self{
+                id: None,
+                r#type:  AxisType::Log,
+                name: Some(name),
+                inverse: Some(inverse),
+                data: None,
+                extra: None,
+            }
+        }else {
+            Self{
+                id: None,
+                r#type:  T::AxisType::AXIS_TYPE,
+                name: Some(name.to_string()),
+                inverse: Some(inverse),
+                data: None,
+                extra: None,
+            }
+        }
+    }
+
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+}
+
+
+/// Available series types in ECharts
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum SeriesType {
+    Line,
+    Bar,
+    Pie,
+    Scatter,
+    EffectScatter,
+    Radar,
+    Tree,
+    Treemap,
+    Sunburst,
+    Boxplot,
+    Candlestick,
+    Heatmap,
+    Map,
+    Parallel,
+    Lines,
+    Graph,
+    Sankey,
+    Funnel,
+    Gauge,
+    PictorialBar,
+    ThemeRiver,
+    Custom
+}
+
+
+#[derive(Serialize,  Debug, Clone)]
+#[serde(bound = "")]
+#[serde(untagged)]
+pub enum DataVariant<X:AxisKindMarker,Y:AxisKindMarker>{
+    /// Single dimension data (string/int/float)
     Data(Vec<ValueSerializeWrapper<X>>),
 
-    /// Two-dimensional data: [x, y]
-    Pair(Vec<(ValueSerializeWrapper<X>,ValueSerializeWrapper<Y>)>),
+    /// Two-dimensional data: [x, y]
+    Pair(Vec<(ValueSerializeWrapper<X>,ValueSerializeWrapper<Y>)>),
+
+    /// Single dimension data as an object with a name field
+    Named(Vec<NamedValue<X>>),
+
+    /// Two-dimensional data with the name: { value: [x, y], name: "str" }
+    NamedPair(Vec<NamedValuePair<X,Y>>),
+}
+
+/// Internal enum to represent the data source for a series
+#[derive(Serialize, Debug, Clone)]
+#[serde(bound = "")]
+#[serde(rename_all = "camelCase")]
+pub enum SeriesDataSource<X:AxisKindMarker,Y:AxisKindMarker>{
+    /// Direct data
+    Data(DataVariant<X,Y>),
+    /// Reference to a dataset by index
+    DatasetIndex(usize)
+}
+
+impl<X:AxisKindMarker,Y:AxisKindMarker> From<Vec<(X, Y)>> for SeriesDataSource<X,Y>
+{
+    fn from(value: Vec<(X, Y)>) -> Self {
+        SeriesDataSource::from_pairs(value)
+    }
+}
+
+impl<const N: usize,X:AxisKindMarker,Y:AxisKindMarker> From<[(X, Y); N]> for SeriesDataSource<X,Y>
+{
+    fn from(value: [(X, Y); N]) -> Self {
+        Self::Data(DataVariant::Pair(value.into_iter().map(|(x, y)| (x.into(), y.into())).collect()))
+    }
+}
+
+impl<X:AxisKindMarker,Y:AxisKindMarker> From<Vec<(X, Y, String)>> for SeriesDataSource<X,Y>
+{
+    fn from(value: Vec<(X, Y, String)>) -> Self {
+        SeriesDataSource::from_tuples_with_label(value)
+    }
+}
+
+impl<X:AxisKindMarker,Y:AxisKindMarker> From<Vec<X>> for SeriesDataSource<X,Y>
+{
+    fn from(value: Vec<X>) -> Self {
+        SeriesDataSource::from_values(value)
+    }
+}
+
+impl<X:AxisKindMarker,Y:AxisKindMarker> SeriesDataSource<X,Y> {
+    /// Creates a new SeriesDataSource that references a dataset by index
+    pub fn from_dataset_index(index: usize) -> Self {
+        Self::DatasetIndex(index)
+    }
+
+    /// Creates a SeriesDataSource with a simple array of values
+    pub fn from_values(values: Vec<X>) -> Self {
+        Self::Data(DataVariant::Data(values.into_iter().map(ValueSerializeWrapper::from).collect()))
+    }
+
+    /// Creates a SeriesDataSource with an array of [x,y] pairs
+    pub fn from_pairs(pairs: Vec<(X,Y)>) -> Self {
+        Self::Data(DataVariant::Pair(pairs.into_iter().map(|(x,y)| (x.into(),y.into())).collect()))
+    }
+
+   pub fn from_named_value_pairs(named_pairs: Vec<NamedValuePair<X,Y>>) -> Self{
+       Self::Data(DataVariant::NamedPair(named_pairs))
+   }
+
+
+    /// Creates a SeriesDataSource with named values
+    pub fn with_named_values(named_values: Vec<NamedValue<X>>) -> Self {
+        Self::Data(DataVariant::Named(named_values))
+    }
+
+    pub fn from_labeled_values(values: Vec<(X, String)>) -> Self
+    {
+        let data_named_values = values.into_iter().map(|(x,label)| NamedValue::new(x, label)).collect();
+        Self::with_named_values(data_named_values)
+    }
+
+    /// Creates a SeriesDataSource with named pairs
+    pub fn from_tuples_with_label(values: Vec<(X, Y, String)>) -> Self
+    {
+        let data_named_pairs = values.into_iter()
+            .map(|(x, y,label) | NamedValuePair::new(x, y, label))
+            .collect();
+        Self::from_named_value_pairs(data_named_pairs)
+    }
+
+}
+
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum DataPointSymbol {
+    Circle,
+    Rect,
+    RoundRect,
+    Triangle,
+    Diamond,
+    Pin,
+    Arrow,
+    None
+}
+
+/// Series definition
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Series<X:AxisKindMarker,Y:AxisKindMarker> {
+    /// Component id, used by ECharts to match components when merging `setOption` calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Chart type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<SeriesType>,
+
+    /// Series name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smooth: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area_style: Option<AreaStyle>,
+
+    /// Data array
+    #[serde(flatten)]
+    pub data: SeriesDataSource<X,Y>,
+
+    /// if showSymbol is false symbol will still show on hover
+    /// if the tooltip trigger is an axis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_symbol: Option<bool>,
+
+    ///if the show symbol is none, line won't react on hover anymore
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<DataPointSymbol>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol_size: Option<usize>,
+
+    /// Style of the data points (bars, symbols, pie sectors)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_style: Option<ItemStyle>,
+
+    /// Style of the line for line-like series
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_style: Option<LineStyle>,
+
+    /// Text label displayed on data points
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<Label>,
+
+    /// Style of the highlighted state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emphasis: Option<Emphasis>,
+
+    /// Style of the faded out state, when another item is in focus
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blur: Option<StateStyle>,
+
+    /// Style of the selected state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub select: Option<StateStyle>,
+
+    /// Points annotating the series, e.g. its maximum
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mark_point: Option<MarkPoint<X,Y>>,
+
+    /// Lines annotating the series, e.g. thresholds or the average
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mark_line: Option<MarkLine<X,Y>>,
+
+    /// Areas annotating the series, e.g. incident time ranges
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mark_area: Option<MarkArea<X,Y>>,
+
+    /// Additional raw series options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
+}
+
+impl<X:AxisKindMarker,Y:AxisKindMarker> Series <X,Y>{
+    pub fn new(name:String, r#type: SeriesType, data: SeriesDataSource<X,Y>) -> Series<X,Y> {
+        Self{
+            id: None,
+            r#type: Some(r#type),
+            name: Some(name),
+            smooth: None,
+            area_style: None,
+            data,
+            show_symbol: None,
+            symbol: None,
+            symbol_size: None,
+            item_style: None,
+            line_style: None,
+            label: None,
+            emphasis: None,
+            blur: None,
+            select: None,
+            mark_point: None,
+            mark_line: None,
+            mark_area: None,
+            extra: None,
+        }
+    }
+
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    pub fn item_style(mut self, item_style: ItemStyle) -> Self {
+        self.item_style = Some(item_style);
+        self
+    }
+
+    pub fn line_style(mut self, line_style: LineStyle) -> Self {
+        self.line_style = Some(line_style);
+        self
+    }
+
+    pub fn area_style(mut self, area_style: AreaStyle) -> Self {
+        self.area_style = Some(area_style);
+        self
+    }
+
+    pub fn label(mut self, label: Label) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn emphasis(mut self, emphasis: Emphasis) -> Self {
+        self.emphasis = Some(emphasis);
+        self
+    }
+
+    pub fn blur(mut self, blur: StateStyle) -> Self {
+        self.blur = Some(blur);
+        self
+    }
+
+    pub fn select(mut self, select: StateStyle) -> Self {
+        self.select = Some(select);
+        self
+    }
+
+    pub fn mark_point(mut self, mark_point: MarkPoint<X,Y>) -> Self {
+        self.mark_point = Some(mark_point);
+        self
+    }
+
+    pub fn mark_line(mut self, mark_line: MarkLine<X,Y>) -> Self {
+        self.mark_line = Some(mark_line);
+        self
+    }
+
+    pub fn mark_area(mut self, mark_area: MarkArea<X,Y>) -> Self {
+        self.mark_area = Some(mark_area);
+        self
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum AxisFillOrigin{
+    Auto,
+    Start,
+    End,
+    Number
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AreaStyle{
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin: Option<AxisFillOrigin>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opacity: Option<f64>
+}
+
+/// Line dash keywords supported by ECharts
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum LineTypeKeyword {
+    Solid,
+    Dashed,
+    Dotted
+}
+
+/// Line dash, either a keyword or a dash array (e.g. [5, 10])
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum LineType {
+    Keyword(LineTypeKeyword),
+    Dash(Vec<f64>)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum LineCap {
+    Butt,
+    Round,
+    Square
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum LineJoin {
+    Bevel,
+    Round,
+    Miter
+}
+
+/// Style of lines (series lines, borders of lines, axis lines)
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LineStyle {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<f64>,
+
+    /// Solid, dashed, dotted or a custom dash array
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<LineType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dash_offset: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cap: Option<LineCap>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub join: Option<LineJoin>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow_blur: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow_color: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow_offset_x: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow_offset_y: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opacity: Option<f64>,
+
+    /// Additional raw line style options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
+}
+
+/// Style of data items (bars, symbols, pie sectors)
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemStyle {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border_color: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border_width: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border_type: Option<LineType>,
+
+    /// Corner radius of bars, either one value or [top-left, top-right, bottom-right, bottom-left]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border_radius: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow_blur: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow_color: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow_offset_x: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow_offset_y: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opacity: Option<f64>,
+
+    /// Additional raw item style options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
+}
+
+/// Label placement relative to the data point
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum LabelPosition {
+    Top,
+    Left,
+    Right,
+    Bottom,
+    Inside,
+    InsideLeft,
+    InsideRight,
+    InsideTop,
+    InsideBottom,
+    InsideTopLeft,
+    InsideBottomLeft,
+    InsideTopRight,
+    InsideBottomRight,
+    Outside,
+    Start,
+    Middle,
+    End
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum FontWeight {
+    Normal,
+    Bold,
+    Bolder,
+    Lighter
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum FontStyle {
+    Normal,
+    Italic,
+    Oblique
+}
+
+/// Text label of data items
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Label {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<LabelPosition>,
+
+    /// Distance to the host graphic element in px
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
+
+    /// Rotation in degrees, from -90 to 90
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rotate: Option<f64>,
+
+    /// Label formatter template (e.g. "{b}: {c}")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatter: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_style: Option<FontStyle>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_weight: Option<FontWeight>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_family: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border_color: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border_width: Option<f64>,
 
-    /// Single dimension data as an object with a name field
-    Named(Vec<NamedValue<X>>),
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border_radius: Option<f64>,
 
-    /// Two-dimensional data with the name: { value: [x, y], name: "str" }
-    NamedPair(Vec<NamedValuePair<X,Y>>),
-}
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub padding: Option<Value>,
 
-/// Internal enum to represent the data source for a series
-#[derive(Serialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub enum SeriesDataSource<X:AxisKindMarker,Y:AxisKindMarker>{
-    /// Direct data
-    Data(DataVariant<X,Y>),
-    /// Reference to a dataset by index
-    DatasetIndex(usize)
+    /// Additional raw label options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
 }
 
-impl<X:AxisKindMarker,Y:AxisKindMarker> From<Vec<(X, Y)>> for SeriesDataSource<X,Y>
-{
-    fn from(value: Vec<(X, Y)>) -> Self {
-        SeriesDataSource::from_pairs(value)
+impl Label {
+    /// Label that is shown at the given position
+    pub fn shown(position: LabelPosition) -> Self {
+        Self{
+            show: Some(true),
+            position: Some(position),
+            ..Default::default()
+        }
     }
 }
 
-impl<const N: usize,X:AxisKindMarker,Y:AxisKindMarker> From<[(X, Y); N]> for SeriesDataSource<X,Y>
-{
-    fn from(value: [(X, Y); N]) -> Self {
-        Self::Data(DataVariant::Pair(value.into_iter().map(|(x, y)| (x.into(), y.into())).collect()))
-    }
+/// Which elements stay highlighted when a data point is in focus
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum EmphasisFocus {
+    None,
+    #[serde(rename = "self")]
+    Itself,
+    Series
 }
 
-impl<X:AxisKindMarker,Y:AxisKindMarker> From<Vec<(X, Y, String)>> for SeriesDataSource<X,Y>
-{
-    fn from(value: Vec<(X, Y, String)>) -> Self {
-        SeriesDataSource::from_tuples_with_label(value)
-    }
+/// Range of the elements faded out when a data point is in focus
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum BlurScope {
+    CoordinateSystem,
+    Series,
+    Global
 }
 
-impl<X:AxisKindMarker,Y:AxisKindMarker> From<Vec<X>> for SeriesDataSource<X,Y>
-{
-    fn from(value: Vec<X>) -> Self {
-        SeriesDataSource::from_values(value)
-    }
-}
+/// Highlighted (hover) state of a series or data point
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Emphasis {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
 
-impl<X:AxisKindMarker,Y:AxisKindMarker> SeriesDataSource<X,Y> {
-    /// Creates a new SeriesDataSource that references a dataset by index
-    pub fn from_dataset_index(index: usize) -> Self {
-        Self::DatasetIndex(index)
-    }
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus: Option<EmphasisFocus>,
 
-    /// Creates a SeriesDataSource with a simple array of values
-    pub fn from_values(values: Vec<X>) -> Self {
-        Self::Data(DataVariant::Data(values.into_iter().map(ValueSerializeWrapper::from).collect()))
-    }
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blur_scope: Option<BlurScope>,
 
-    /// Creates a SeriesDataSource with an array of [x,y] pairs
-    pub fn from_pairs(pairs: Vec<(X,Y)>) -> Self {
-        Self::Data(DataVariant::Pair(pairs.into_iter().map(|(x,y)| (x.into(),y.into())).collect()))
-    }
+    /// Whether to scale up the symbol or bar on hover
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale: Option<bool>,
 
-   pub fn from_named_value_pairs(named_pairs: Vec<NamedValuePair<X,Y>>) -> Self{
-       Self::Data(DataVariant::NamedPair(named_pairs))
-   }
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_style: Option<ItemStyle>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_style: Option<LineStyle>,
 
-    /// Creates a SeriesDataSource with named values
-    pub fn with_named_values(named_values: Vec<NamedValue<X>>) -> Self {
-        Self::Data(DataVariant::Named(named_values))
-    }
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area_style: Option<AreaStyle>,
 
-    pub fn from_labeled_values(values: Vec<(X, String)>) -> Self
-    {
-        let data_named_values = values.into_iter().map(|(x,label)| NamedValue{
-            value: x.into(),
-            name: label
-        }).collect();
-        Self::with_named_values(data_named_values)
-    }
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<Label>,
 
-    /// Creates a SeriesDataSource with named pairs
-    pub fn from_tuples_with_label(values: Vec<(X, Y, String)>) -> Self
-    {
-        let data_named_pairs = values.into_iter()
-            .map(|(x, y,label) | NamedValuePair::new(x.into(), y.into(),label))
-            .collect();
-        Self::from_named_value_pairs(data_named_pairs)
-    }
+    /// Additional raw emphasis options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
+}
+
+/// Blur or select state of a series or data point
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StateStyle {
+    /// Only meaningful for the select state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_style: Option<ItemStyle>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_style: Option<LineStyle>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area_style: Option<AreaStyle>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<Label>,
+
+    /// Additional raw state options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
 }
 
+/// Statistic of the series data used as an annotation position
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum MarkStatistic {
+    Min,
+    Max,
+    Average,
+    /// Only supported by markLine
+    Median
+}
 
+/// Dimension the statistic is calculated on
 #[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub enum DataPointSymbol {
-    Circle,
-    Rect,
-    RoundRect,
-    Triangle,
-    Diamond,
-    Pin,
-    Arrow,
-    None
+#[serde(rename_all = "lowercase")]
+pub enum MarkDimension {
+    X,
+    Y
 }
 
-/// Series definition
+/// Position of an annotation, either a statistic of the series or fixed axis values.
+/// Fixed values are serialized the same way as the series data
 #[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct Series<X:AxisKindMarker,Y:AxisKindMarker> {
-    /// Chart type
+pub struct MarkData<X:AxisKindMarker,Y:AxisKindMarker> {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub r#type: Option<SeriesType>,
+    pub r#type: Option<MarkStatistic>,
 
-    /// Series name
+    /// Dimension of the statistic, the value axis by default
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub value_dim: Option<MarkDimension>,
 
+    /// Vertical line or edge at the given x value
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub smooth: Option<bool>,
+    pub x_axis: Option<ValueSerializeWrapper<X>>,
 
+    /// Horizontal line or edge at the given y value
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub area_style: Option<AreaStyle>,
+    pub y_axis: Option<ValueSerializeWrapper<Y>>,
 
-    /// Data array
-    #[serde(flatten)]
-    pub data: SeriesDataSource<X,Y>,
+    /// Point at the given x and y values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coord: Option<(ValueSerializeWrapper<X>,ValueSerializeWrapper<Y>)>,
 
-    /// if showSymbol is false symbol will still show on hover
-    /// if the tooltip trigger is an axis
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub show_symbol: Option<bool>,
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<Label>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_style: Option<ItemStyle>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_style: Option<LineStyle>,
+}
+
+impl<X:AxisKindMarker,Y:AxisKindMarker> MarkData<X,Y> {
+
+    fn empty() -> Self {
+        Self{
+            r#type: None,
+            value_dim: None,
+            x_axis: None,
+            y_axis: None,
+            coord: None,
+            name: None,
+            label: None,
+            item_style: None,
+            line_style: None,
+        }
+    }
+
+    /// Statistic of the series values
+    pub fn statistic(statistic: MarkStatistic) -> Self {
+        Self{
+            r#type: Some(statistic),
+            ..Self::empty()
+        }
+    }
+
+    /// Statistic calculated on the given dimension, e.g. for horizontal bars
+    pub fn statistic_of(statistic: MarkStatistic, dimension: MarkDimension) -> Self {
+        Self{
+            r#type: Some(statistic),
+            value_dim: Some(dimension),
+            ..Self::empty()
+        }
+    }
+
+    pub fn x(x: X) -> Self {
+        Self{
+            x_axis: Some(x.into()),
+            ..Self::empty()
+        }
+    }
+
+    pub fn y(y: Y) -> Self {
+        Self{
+            y_axis: Some(y.into()),
+            ..Self::empty()
+        }
+    }
+
+    pub fn coord(x: X, y: Y) -> Self {
+        Self{
+            coord: Some((x.into(), y.into())),
+            ..Self::empty()
+        }
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn label(mut self, label: Label) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn item_style(mut self, item_style: ItemStyle) -> Self {
+        self.item_style = Some(item_style);
+        self
+    }
+
+    pub fn line_style(mut self, line_style: LineStyle) -> Self {
+        self.line_style = Some(line_style);
+        self
+    }
+}
+
+/// markPoint, symbols placed at points of the series
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPoint<X:AxisKindMarker,Y:AxisKindMarker> {
+    pub data: Vec<MarkData<X,Y>>,
 
-    ///if the show symbol is none, line won't react on hover anymore
     #[serde(skip_serializing_if = "Option::is_none")]
     pub symbol: Option<DataPointSymbol>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub symbol_size: Option<usize>,
 
-    /// Additional raw series options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<Label>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_style: Option<ItemStyle>,
+
+    /// Additional raw markPoint options
     #[serde(flatten)]
     pub extra: Option<Value>,
 }
 
-impl<X:AxisKindMarker,Y:AxisKindMarker> Series <X,Y>{
-    pub fn new(name:String, r#type: SeriesType, data: SeriesDataSource<X,Y>) -> Series<X,Y> {
+impl<X:AxisKindMarker,Y:AxisKindMarker> MarkPoint<X,Y> {
+    pub fn new(data: Vec<MarkData<X,Y>>) -> Self {
         Self{
-            r#type: Some(r#type),
-            name: Some(name),
-            smooth: None,
-            area_style: None,
             data,
-            show_symbol: None,
             symbol: None,
             symbol_size: None,
+            label: None,
+            item_style: None,
             extra: None,
         }
     }
 }
 
+/// Line of a markLine, either across the whole grid or between two points
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum MarkLineData<X:AxisKindMarker,Y:AxisKindMarker> {
+    /// Horizontal or vertical line through a statistic or an axis value
+    Line(Box<MarkData<X,Y>>),
+    /// Line from the first position to the second
+    Segment(Box<(MarkData<X,Y>, MarkData<X,Y>)>)
+}
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+impl<X:AxisKindMarker,Y:AxisKindMarker> From<MarkData<X,Y>> for MarkLineData<X,Y> {
+    fn from(value: MarkData<X,Y>) -> Self {
+        MarkLineData::Line(Box::new(value))
+    }
+}
+
+impl<X:AxisKindMarker,Y:AxisKindMarker> From<(MarkData<X,Y>, MarkData<X,Y>)> for MarkLineData<X,Y> {
+    fn from(value: (MarkData<X,Y>, MarkData<X,Y>)) -> Self {
+        MarkLineData::Segment(Box::new(value))
+    }
+}
+
+/// markLine, lines annotating the series
+#[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub enum AxisFillOrigin{
-    Auto,
-    Start,
-    End,
-    Number
+pub struct MarkLine<X:AxisKindMarker,Y:AxisKindMarker> {
+    pub data: Vec<MarkLineData<X,Y>>,
+
+    /// Symbols at the start and the end of the lines
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<(DataPointSymbol, DataPointSymbol)>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<Label>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_style: Option<LineStyle>,
+
+    /// Ignore mouse events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub silent: Option<bool>,
+
+    /// Additional raw markLine options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+impl<X:AxisKindMarker,Y:AxisKindMarker> MarkLine<X,Y> {
+    pub fn new(data: Vec<MarkLineData<X,Y>>) -> Self {
+        Self{
+            data,
+            symbol: None,
+            label: None,
+            line_style: None,
+            silent: None,
+            extra: None,
+        }
+    }
+}
+
+/// markArea, rectangles annotating the series, each spanning from the first position to the second
+#[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct AreaStyle{
+pub struct MarkArea<X:AxisKindMarker,Y:AxisKindMarker> {
+    pub data: Vec<(MarkData<X,Y>, MarkData<X,Y>)>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<Label>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
-    color: Option<Value>,
+    pub item_style: Option<ItemStyle>,
+
+    /// Ignore mouse events
     #[serde(skip_serializing_if = "Option::is_none")]
-    origin: Option<AxisFillOrigin>
+    pub silent: Option<bool>,
+
+    /// Additional raw markArea options
+    #[serde(flatten)]
+    pub extra: Option<Value>,
+}
+
+impl<X:AxisKindMarker,Y:AxisKindMarker> MarkArea<X,Y> {
+    pub fn new(data: Vec<(MarkData<X,Y>, MarkData<X,Y>)>) -> Self {
+        Self{
+            data,
+            label: None,
+            item_style: None,
+            silent: None,
+            extra: None,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Transform{
+    /// Dataset id, used by ECharts to match datasets when merging `setOption` calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
     pub transform: Vec<DatasetTransform>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -625,12 +2239,17 @@ pub struct Transform{
 #[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Source<X:AxisKindMarker,Y:AxisKindMarker>{
+    /// Dataset id, used by ECharts to match datasets when merging `setOption` calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
     pub source: Vec<(ValueSerializeWrapper<X>,ValueSerializeWrapper<Y>)>,
 }
 
 impl<X:AxisKindMarker,Y:AxisKindMarker> From<Vec<(X,Y)>> for Source<X,Y>{
     fn from(value: Vec<(X,Y)>) -> Self {
         Self{
+            id: None,
             source: value.into_iter().map(|(x,y)| (x.into(),y.into())).collect()
         }
     }
@@ -640,6 +2259,7 @@ impl<const N:usize,X:AxisKindMarker,Y:AxisKindMarker> From<[(X,Y);N]> for Source
 {
     fn from(value: [(X,Y);N]) -> Self {
         Self{
+            id: None,
             source: value.into_iter().map(|(x,y)| (x.into(),y.into())).collect()
         }
     }
@@ -649,6 +2269,10 @@ impl<const N:usize,X:AxisKindMarker,Y:AxisKindMarker> From<[(X,Y);N]> for Source
 #[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct LabelledSource<X:AxisKindMarker,Y:AxisKindMarker>{
+    /// Dataset id, used by ECharts to match datasets when merging `setOption` calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
     pub source: Vec<(ValueSerializeWrapper<X>,ValueSerializeWrapper<Y>,String)>,
 }
 
@@ -687,6 +2311,7 @@ impl<const N:usize,X:AxisKindMarker,Y:AxisKindMarker>  From<[(X,Y,String);N]> fo
     fn from(value: [(X,Y,String);N] ) -> Self {
         Self::LabelledSource(
             LabelledSource{
+                id: None,
                 source: value.into_iter().map(|(x,y,label)| (x.into(),y.into(),label)).collect()
             }
         )
@@ -704,6 +2329,7 @@ impl<X:AxisKindMarker,Y:AxisKindMarker> From<Vec<(X,Y,String)>> for DatasetCompo
 impl<X:AxisKindMarker,Y:AxisKindMarker> DatasetComponent<X,Y> {
     pub fn tr(transform: DatasetTransform, index: usize) -> Self{
         Self::Transform(Transform {
+            id: None,
             transform: vec![transform],
             from_dataset_index: Some(index),
         })
@@ -712,6 +2338,7 @@ impl<X:AxisKindMarker,Y:AxisKindMarker> DatasetComponent<X,Y> {
     pub fn trs(transform: Vec<DatasetTransform>, index: usize) -> Self{
         Self::Transform(
             Transform {
+                id: None,
                 transform,
                 from_dataset_index: Some(index)
             }
@@ -726,10 +2353,22 @@ impl<X:AxisKindMarker,Y:AxisKindMarker> DatasetComponent<X,Y> {
     pub fn labelled_source(source: Vec<(X,Y,String)>) -> Self {
         Self::LabelledSource(
             LabelledSource{
+                id: None,
                 source: source.into_iter().map(|(x,y,label)| (x.into(),y.into(),label)).collect()
             }
         )
     }
+
+    /// Set the id of the dataset, matched when options are merged
+    pub fn id(mut self, id: &str) -> Self {
+        let target = match &mut self {
+            Self::Source(source) => &mut source.id,
+            Self::LabelledSource(source) => &mut source.id,
+            Self::Transform(transform) => &mut transform.id,
+        };
+        *target = Some(id.to_string());
+        self
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -0,0 +1,167 @@
+use serde_json::{json, Value};
+use crate::axis_typing::AxisKindMarker;
+use crate::common;
+use crate::options::*;
+use crate::options::Position::Percent;
+
+/// Reusable set of styles that can be applied to any chart in one call.
+/// Only the fields that are set on the preset are written to the chart,
+/// everything else is left as is.
+#[derive(Debug, Clone, Default)]
+pub struct StylePreset {
+    /// Color palette for the series
+    pub color: Option<Vec<Value>>,
+
+    /// Background color of the chart
+    pub background_color: Option<Value>,
+
+    /// Global font settings
+    pub text_style: Option<TextStyle>,
+
+    /// Grid paddings, only left, right, top, bottom and contain_label are applied
+    pub grid: Option<Grid>,
+
+    /// Tooltip look, only the background, border, padding and text style are applied.
+    /// Charts without a tooltip are left without one
+    pub tooltip: Option<Tooltip>,
+
+    /// Legend placement, orient, left, right, top and bottom replace the ones of the chart
+    pub legend: Option<Legend>,
+}
+
+fn palette(colors: &[&str]) -> Option<Vec<Value>> {
+    Some(colors.iter().map(|c| json!(c)).collect())
+}
+
+fn tooltip_look(background_color: &str, border_color: &str, text_color: &str) -> Tooltip {
+    Tooltip {
+        show: true,
+        show_delay: None,
+        hide_delay: None,
+        trigger: None,
+        formatter: None,
+        axis_pointer: None,
+        background_color: Some(json!(background_color)),
+        border_color: Some(json!(border_color)),
+        border_width: Some(1.0),
+        padding: Some(json!(8)),
+        text_style: Some(TextStyle {
+            color: Some(json!(text_color)),
+            ..Default::default()
+        }),
+    }
+}
+
+impl StylePreset {
+
+    /// Soft colors on a white background with the legend on top
+    pub fn pastel() -> Self {
+        Self {
+            color: palette(&["#5b8ff9", "#5ad8a6", "#f6bd16", "#e86452", "#6dc8ec", "#945fb9", "#ff9845", "#1e9493"]),
+            background_color: Some(json!("#ffffff")),
+            text_style: Some(TextStyle {
+                color: Some(json!("#595959")),
+                font_family: Some("sans-serif".to_string()),
+                font_size: Some(12.0),
+                ..Default::default()
+            }),
+            grid: Some(Grid {
+                left: Some(Position::Number(48.0)),
+                right: Some(Position::Number(24.0)),
+                top: Some(Position::Number(64.0)),
+                bottom: Some(Position::Number(40.0)),
+                contain_label: Some(true),
+                ..Default::default()
+            }),
+            tooltip: Some(tooltip_look("rgba(255, 255, 255, 0.95)", "#d9d9d9", "#262626")),
+            legend: Some(Legend {
+                orient: Some(LegendOrient::Horizontal),
+                top: Some(Position::Number(28.0)),
+                left: Some(Position::Keyword(PositionKeyword::Center)),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Light text and vivid colors on a dark background
+    pub fn dark() -> Self {
+        Self {
+            color: palette(&["#4992ff", "#7cffb2", "#fddd60", "#ff6e76", "#58d9f9", "#05c091", "#ff8a45", "#8d48e3"]),
+            background_color: Some(json!("#100c2a")),
+            text_style: Some(TextStyle {
+                color: Some(json!("#eeeeee")),
+                font_family: Some("sans-serif".to_string()),
+                font_size: Some(12.0),
+                ..Default::default()
+            }),
+            grid: None,
+            tooltip: Some(tooltip_look("rgba(50, 50, 50, 0.9)", "#333333", "#eeeeee")),
+            legend: None,
+        }
+    }
+
+    /// Small paddings and a vertical legend on the right, for dashboards with many small charts
+    pub fn compact() -> Self {
+        Self {
+            color: None,
+            background_color: None,
+            text_style: Some(TextStyle {
+                font_size: Some(10.0),
+                ..Default::default()
+            }),
+            grid: Some(Grid {
+                left: Some(Position::Number(8.0)),
+                right: Some(Percent(common::Percent(20.0))),
+                top: Some(Position::Number(32.0)),
+                bottom: Some(Position::Number(8.0)),
+                contain_label: Some(true),
+                ..Default::default()
+            }),
+            tooltip: None,
+            legend: Some(Legend {
+                orient: Some(LegendOrient::Vertical),
+                right: Some(Percent(common::Percent(0.0))),
+                top: Some(Position::Keyword(PositionKeyword::Middle)),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Write the preset into the chart options
+    pub fn apply<X: AxisKindMarker, Y: AxisKindMarker>(&self, mut options: EChartOptions<X,Y>) -> EChartOptions<X,Y> {
+        if let Some(color) = &self.color {
+            options.color = Some(color.clone());
+        }
+        if let Some(background_color) = &self.background_color {
+            options.background_color = Some(background_color.clone());
+        }
+        if let Some(text_style) = &self.text_style {
+            options.text_style = Some(text_style.clone());
+        }
+        if let Some(preset) = &self.grid {
+            let grid = options.grid.get_or_insert_default();
+            grid.left = preset.left.clone().or(grid.left.take());
+            grid.right = preset.right.clone().or(grid.right.take());
+            grid.top = preset.top.clone().or(grid.top.take());
+            grid.bottom = preset.bottom.clone().or(grid.bottom.take());
+            grid.contain_label = preset.contain_label.or(grid.contain_label);
+        }
+        if let Some(preset) = &self.tooltip
+            && let Some(tooltip) = options.tooltip.as_mut() {
+            tooltip.background_color = preset.background_color.clone().or(tooltip.background_color.take());
+            tooltip.border_color = preset.border_color.clone().or(tooltip.border_color.take());
+            tooltip.border_width = preset.border_width.or(tooltip.border_width);
+            tooltip.padding = preset.padding.clone().or(tooltip.padding.take());
+            tooltip.text_style = preset.text_style.clone().or(tooltip.text_style.take());
+        }
+        if let Some(preset) = &self.legend {
+            let legend = options.legend.get_or_insert_default();
+            legend.orient = preset.orient.clone();
+            legend.left = preset.left.clone();
+            legend.right = preset.right.clone();
+            legend.top = preset.top.clone();
+            legend.bottom = preset.bottom.clone();
+        }
+        options
+    }
+}
@@ -1,28 +1,345 @@
-use crate::options::{EChartOptions};
+use crate::options::{DatasetComponent, EChartOptions, SeriesDataSource};
 use sailfish::TemplateSimple;
-use serde::{Serialize};
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use crate::axis_typing::AxisKindMarker;
-use crate::common::Size;
+use crate::common::{escape_script, JsonWithFunctions, ScriptJson, Size};
+use crate::theme::ChartTheme;
+use crate::events::{BrushSelectedHandler, ChartEvent, EventAction, EventHandler};
+use crate::timeline::TimelineOptions;
+use crate::live::LiveSource;
+use crate::accessibility::{self, DataTableContent};
 
-#[derive(TemplateSimple)]
+/// Option object passed to `setOption`
+#[derive(Serialize, Debug, Clone)]
+#[serde(bound = "EChartOptions<X,Y>: Serialize")]
+#[serde(untagged)]
+enum ChartOptions<X:AxisKindMarker,Y:AxisKindMarker> {
+    Single(EChartOptions<X,Y>),
+    Timeline(TimelineOptions<X,Y>),
+    /// Options serialized elsewhere, e.g. passed through a template engine context
+    Raw(Value)
+}
+
+impl<X:AxisKindMarker,Y:AxisKindMarker> ChartOptions<X,Y>
+where EChartOptions<X,Y>: Serialize {
+
+    /// Aria description, also rendered as visually hidden text next to the chart
+    fn aria_description(&self) -> Option<&str> {
+        let base = match self {
+            ChartOptions::Single(options) => options,
+            ChartOptions::Timeline(timeline) => &timeline.base,
+            ChartOptions::Raw(value) => return value.pointer("/aria/label/description")?.as_str(),
+        };
+        base.aria.as_ref()?.label.as_ref()?.description.as_deref()
+    }
+
+    /// Values shown in the data table, timeline charts show the data of the base option
+    fn data_table(&self) -> DataTableContent {
+        let json = match self {
+            ChartOptions::Single(options) => serde_json::to_value(options),
+            ChartOptions::Timeline(timeline) => serde_json::to_value(&timeline.base),
+            ChartOptions::Raw(value) => return accessibility::data_table(value),
+        };
+        accessibility::data_table(&json.unwrap_or_default())
+    }
+}
+
+#[derive(TemplateSimple, Clone)]
 #[template(path = "chart.stpl")]
 pub struct ScriptTemplate<X:AxisKindMarker,Y:AxisKindMarker>
 where EChartOptions<X,Y>: Serialize{
     chart_target_id: String,
     width: Size,
     height: Size,
-    options: EChartOptions<X,Y>
+    options: ChartOptions<X,Y>,
+    theme: Option<ChartTheme>,
+    init_options: Option<InitOptions>,
+    auto_resize: Option<AutoResize>,
+    brush_selected: Option<BrushSelectedHandler>,
+    event_handlers: Vec<EventHandler>,
+    data_table: Option<DataTable>,
+    htmx: bool,
+    group: Option<String>,
+    live: Option<LiveSource>
+}
+
+/// How the data table rendered next to the chart is shown
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataTable {
+    /// Visually hidden, read by screen readers only
+    Hidden,
+    /// Shown only when scripts are disabled
+    NoScript,
+    /// Collapsed under a "Data table" toggle below the chart
+    Toggle,
+}
+
+/// Events the generated script registers handlers for, switched off when an htmx chart instance is reused
+fn registered_events<'a>(handlers: &'a [EventHandler], brush_selected: &Option<BrushSelectedHandler>) -> Vec<&'a str> {
+    let mut events: Vec<&str> = Vec::new();
+    for name in handlers.iter().map(|h| h.event.name()) {
+        if !events.contains(&name) {
+            events.push(name);
+        }
+    }
+    if brush_selected.is_some() {
+        events.push("brushSelected");
+    }
+    events
+}
+
+/// Resize the chart together with its container.
+/// Uses `ResizeObserver` on the chart div, or `window.resize` in browsers without it.
+/// The observer and the chart are disposed when htmx removes the chart div from the page
+#[derive(Debug, Clone)]
+pub struct AutoResize {
+    /// Delay in milliseconds after the last size change before the chart is redrawn
+    pub debounce_ms: u32,
+
+    /// Duration of the resize animation in milliseconds, no animation when not set
+    pub animation_duration_ms: Option<u32>,
+}
+
+impl Default for AutoResize {
+    fn default() -> Self {
+        Self {
+            debounce_ms: 100,
+            animation_duration_ms: None,
+        }
+    }
+}
+
+/// Rendering engine used by the chart
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Renderer {
+    Canvas,
+    /// Crisp output when printing or zooming the page
+    Svg
+}
+
+/// Locales of the ECharts built-in strings (toolbox titles, aria descriptions, etc.).
+/// Every locale except `EN` and `ZH` requires the matching `echarts/i18n/lang*.js` script on the page
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum Locale {
+    #[serde(rename = "EN")]
+    En,
+    #[serde(rename = "ZH")]
+    Zh,
+    #[serde(rename = "DE")]
+    De,
+    #[serde(rename = "FR")]
+    Fr,
+    #[serde(rename = "ES")]
+    Es,
+    #[serde(rename = "IT")]
+    It,
+    #[serde(rename = "JA")]
+    Ja,
+    #[serde(rename = "KO")]
+    Ko,
+    #[serde(rename = "PT-br")]
+    PtBr,
+    #[serde(rename = "RU")]
+    Ru,
+    #[serde(rename = "PL")]
+    Pl,
+    #[serde(rename = "NL")]
+    Nl,
+    #[serde(rename = "UK")]
+    Uk,
+    #[serde(rename = "CS")]
+    Cs,
+    #[serde(rename = "FI")]
+    Fi,
+    #[serde(rename = "SV")]
+    Sv,
+    #[serde(rename = "TR")]
+    Tr,
+    /// Locale registered on the page via `echarts.registerLocale`
+    #[serde(untagged)]
+    Custom(String)
+}
+
+/// Options passed as the third argument of `echarts.init`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct InitOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renderer: Option<Renderer>,
+
+    /// Ratio of device pixels to CSS pixels, defaults to window.devicePixelRatio
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_pixel_ratio: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Locale>,
+
+    /// Only repaint the changed areas, canvas renderer only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_dirty_rect: Option<bool>,
+
+    /// Enlarge the interaction area on touch screens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_coarse_pointer: Option<bool>,
 }
 
 impl<X,Y> ScriptTemplate<X,Y>
 where X: AxisKindMarker, Y: AxisKindMarker, EChartOptions<X,Y>: Serialize{
 
+    /// Chart rendered into a div with the given id
     pub fn new(chart_target_id: String, width: Size, height: Size, options: EChartOptions<X,Y>) -> Self {
+        Self::with_options(chart_target_id, width, height, ChartOptions::Single(options))
+    }
+
+    /// Chart from options serialized elsewhere, they are passed to `setOption` as they are
+    pub fn from_json(chart_target_id: String, width: Size, height: Size, options: Value) -> Self {
+        Self::with_options(chart_target_id, width, height, ChartOptions::Raw(options))
+    }
+
+    pub fn new_timeline(chart_target_id: String, width: Size, height: Size, options: TimelineOptions<X,Y>) -> Self {
+        Self::with_options(chart_target_id, width, height, ChartOptions::Timeline(options))
+    }
+
+    fn with_options(chart_target_id: String, width: Size, height: Size, options: ChartOptions<X,Y>) -> Self {
         Self {
             chart_target_id,
             width,
             height,
-            options
+            options,
+            theme: None,
+            init_options: None,
+            auto_resize: None,
+            brush_selected: None,
+            event_handlers: Vec::new(),
+            data_table: None,
+            htmx: false,
+            group: None,
+            live: None
+        }
+    }
+
+    /// Put the chart into a group connected with `echarts.connect`, see [`crate::dashboard::ChartGroup`]
+    pub fn group(mut self, group_id: &str) -> Self {
+        self.group = Some(group_id.to_string());
+        self
+    }
+
+    /// Apply the [`crate::live::LiveUpdate`] messages received from the source.
+    /// The connection is closed when htmx removes the chart
+    pub fn live(mut self, source: LiveSource) -> Self {
+        self.live = Some(source);
+        self
+    }
+
+    /// Id of the div the chart is rendered into
+    pub fn chart_target_id(&self) -> &str {
+        &self.chart_target_id
+    }
+
+    /// Render the chart values as an HTML table next to the chart,
+    /// for screen readers and clients without JavaScript
+    pub fn data_table(mut self, mode: DataTable) -> Self {
+        self.data_table = Some(mode);
+        self
+    }
+
+    /// Register a handler for a chart event, e.g. navigate to the details of the clicked bar
+    pub fn on(mut self, event: ChartEvent, action: EventAction) -> Self {
+        self.event_handlers.push(EventHandler { event, action });
+        self
+    }
+
+    /// Forward the data selected with the brush component
+    pub fn on_brush_selected(mut self, handler: BrushSelectedHandler) -> Self {
+        self.brush_selected = Some(handler);
+        self
+    }
+
+    /// Redraw the chart when its container changes size
+    pub fn auto_resize(mut self, auto_resize: AutoResize) -> Self {
+        self.auto_resize = Some(auto_resize);
+        self
+    }
+
+    /// Set renderer, device pixel ratio and locale of the chart instance
+    pub fn init_options(mut self, init_options: InitOptions) -> Self {
+        self.init_options = Some(init_options);
+        self
+    }
+
+    /// Render the chart with the given theme, custom themes are registered in the generated script
+    pub fn theme(mut self, theme: ChartTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Render for htmx swaps. The chart id has to be stable between swaps:
+    /// an instance still attached to the div is reused and its option replaced (`notMerge`),
+    /// and the instance is disposed when htmx removes the div.
+    /// An empty `{id}-update` element is rendered as the target of [`DataUpdate`] fragments
+    pub fn htmx(mut self) -> Self {
+        self.htmx = true;
+        self
+    }
+}
+
+
+/// Renders the chart fragment, for template engines that embed `Display` values.
+/// The output is HTML and must not be escaped by the host template
+impl<X,Y> fmt::Display for ScriptTemplate<X,Y>
+where X: AxisKindMarker + Clone, Y: AxisKindMarker + Clone, EChartOptions<X,Y>: Serialize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let html = self.clone().render_once().map_err(|_| fmt::Error)?;
+        f.write_str(&html)
+    }
+}
+
+/// Series data of a chart, without names and styles of other components
+#[derive(Serialize, Debug, Clone)]
+#[serde(bound = "DatasetComponent<X,Y>: Serialize")]
+struct ChartData<X:AxisKindMarker,Y:AxisKindMarker> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dataset: Option<Vec<DatasetComponent<X,Y>>>,
+    series: Vec<SeriesData<X,Y>>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(bound = "")]
+struct SeriesData<X:AxisKindMarker,Y:AxisKindMarker> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(flatten)]
+    data: SeriesDataSource<X,Y>,
+}
+
+/// Out-of-band htmx fragment replacing only the data of a chart rendered with [`ScriptTemplate::htmx`].
+/// The new data is merged into the existing option, series are matched by id, name or position,
+/// everything else (axes, styles, zoom) stays as it is
+#[derive(TemplateSimple)]
+#[template(path = "data_update.stpl")]
+pub struct DataUpdate<X:AxisKindMarker,Y:AxisKindMarker>
+where DatasetComponent<X,Y>: Serialize {
+    chart_target_id: String,
+    data: ChartData<X,Y>,
+}
+
+impl<X,Y> DataUpdate<X,Y>
+where X: AxisKindMarker, Y: AxisKindMarker, DatasetComponent<X,Y>: Serialize {
+
+    pub fn new(chart_target_id: String, options: EChartOptions<X,Y>) -> Self {
+        Self {
+            chart_target_id,
+            data: ChartData {
+                dataset: options.dataset,
+                series: options.series.unwrap_or_default().into_iter()
+                    .map(|s| SeriesData { id: s.id, name: s.name, data: s.data })
+                    .collect(),
+            },
         }
     }
 }
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+use sailfish::TemplateSimple;
+use serde_json::Value;
+use tera::{Filter, Tera};
+use crate::common::Size;
+use crate::ids::IdStrategy;
+use crate::templates::ScriptTemplate;
+
+fn size(args: &HashMap<String, Value>, name: &str, default: &str) -> tera::Result<Size> {
+    let text = match args.get(name) {
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+        None => default.to_string(),
+    };
+    text.parse().map_err(tera::Error::msg)
+}
+
+/// Filter rendering serialized chart options as the chart fragment:
+/// `{{ options | echarts_chart(width="100%", height="400px", id="sales") }}`.
+/// All arguments are optional, the size defaults to 100% x 400px and the id to the next `echarts-{n}`.
+/// The output is marked safe, so it is not escaped
+pub struct EChartsFilter;
+
+impl Filter for EChartsFilter {
+    fn filter(&self, options: &Value, args: &HashMap<String, Value>) -> tera::Result<Value> {
+        let width = size(args, "width", "100%")?;
+        let height = size(args, "height", "400px")?;
+        let id = match args.get("id") {
+            Some(Value::String(id)) => id.clone(),
+            Some(other) => return Err(tera::Error::msg(format!("chart id must be a string, got {other}"))),
+            None => IdStrategy::Unique.id(options, &width, &height),
+        };
+        ScriptTemplate::<f64, f64>::from_json(id, width, height, options.clone())
+            .render_once()
+            .map(Value::String)
+            .map_err(|e| tera::Error::chain("failed to render chart", e))
+    }
+
+    fn is_safe(&self) -> bool {
+        true
+    }
+}
+
+/// Register the `echarts_chart` filter
+pub fn register(tera: &mut Tera) {
+    tera.register_filter("echarts_chart", EChartsFilter);
+}
@@ -0,0 +1,65 @@
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+use pretty_assertions::StrComparison;
+use serde::Serialize;
+use crate::common::substitute_js_functions;
+
+/// Environment variable making the assertions create missing snapshots and overwrite the stored ones
+pub const UPDATE_VAR: &str = "UPDATE_SNAPSHOTS";
+
+/// Assert the options serialize to the pretty printed JSON stored at the path.
+/// `JsFunction` values are stored as their code, as their serialized tag differs per process
+#[track_caller]
+pub fn assert_json_snapshot<T: Serialize + ?Sized>(path: impl AsRef<Path>, options: &T) {
+    let json = serde_json::to_string_pretty(options)
+        .and_then(|json| substitute_js_functions(&json))
+        .unwrap_or_else(|e| panic!("options are not serializable: {e}"));
+    assert_snapshot(path.as_ref(), &json);
+}
+
+/// Assert the rendered chart or page, e.g. a `ScriptTemplate`, matches the HTML stored at the path
+#[track_caller]
+pub fn assert_html_snapshot(path: impl AsRef<Path>, html: impl Display) {
+    assert_snapshot(path.as_ref(), &html.to_string());
+}
+
+/// Assert the text matches the snapshot stored at the path.
+/// A missing snapshot or a mismatch fails, the latter with a line diff. Run the tests with `UPDATE_SNAPSHOTS=1`
+/// to write the snapshots instead, then review and commit them.
+/// Charts have to be built with a deterministic container id, e.g. `IdStrategy::ContentHash`
+#[track_caller]
+pub fn assert_snapshot(path: &Path, actual: &str) {
+    let update = std::env::var_os(UPDATE_VAR).is_some_and(|v| v != "0");
+    assert_snapshot_with(path, actual, update);
+}
+
+/// [`assert_snapshot`] with the update mode given by the caller instead of `UPDATE_SNAPSHOTS`
+#[track_caller]
+pub fn assert_snapshot_with(path: &Path, actual: &str, update: bool) {
+    if update {
+        return write_snapshot(path, actual);
+    }
+    let expected = fs::read_to_string(path).unwrap_or_else(|e| panic!(
+        "snapshot {} can't be read ({e}), run with {UPDATE_VAR}=1 to create it",
+        path.display(),
+    ));
+    // snapshots may have been checked out with CRLF line endings or lost the final newline
+    let expected = expected.replace("\r\n", "\n");
+    if expected.trim_end() != actual.trim_end() {
+        panic!(
+            "snapshot {} does not match, run with {UPDATE_VAR}=1 to update it\n{}",
+            path.display(),
+            StrComparison::new(expected.trim_end(), actual.trim_end()),
+        );
+    }
+}
+
+#[track_caller]
+fn write_snapshot(path: &Path, content: &str) {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).unwrap_or_else(|e| panic!("failed to create {}: {e}", dir.display()));
+    }
+    fs::write(path, format!("{}\n", content.trim_end()))
+        .unwrap_or_else(|e| panic!("failed to write snapshot {}: {e}", path.display()));
+}
@@ -0,0 +1,124 @@
+use std::path::Path;
+use std::{fs, io};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use crate::options::TextStyle;
+
+/// Theme passed to `echarts.init`
+#[derive(Debug, Clone)]
+pub enum ChartTheme {
+    /// Default ECharts theme
+    Light,
+    /// Dark theme shipped with ECharts
+    Dark,
+    /// Theme that is registered elsewhere on the page (e.g. by a loaded theme script)
+    Named(String),
+    /// Theme defined in Rust, registered via `echarts.registerTheme` before the chart is created
+    Custom { name: String, theme: Box<Theme> },
+}
+
+impl ChartTheme {
+
+    pub fn custom(name: &str, theme: Theme) -> Self {
+        ChartTheme::Custom { name: name.to_string(), theme: Box::new(theme) }
+    }
+
+    /// Name the theme is referred to by in `echarts.init`
+    pub fn name(&self) -> &str {
+        match self {
+            ChartTheme::Light => "light",
+            ChartTheme::Dark => "dark",
+            ChartTheme::Named(name) => name,
+            ChartTheme::Custom { name, .. } => name,
+        }
+    }
+
+    /// Theme object that has to be registered before use, if any
+    pub fn definition(&self) -> Option<&Theme> {
+        match self {
+            ChartTheme::Custom { theme, .. } => Some(theme),
+            _ => None,
+        }
+    }
+}
+
+/// Title styles of a theme
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeTitle {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_style: Option<TextStyle>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtext_style: Option<TextStyle>,
+}
+
+/// ECharts theme object, compatible with the JSON exported by the ECharts theme builder
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Theme {
+    /// Remaining theme sections (line, bar, categoryAxis, legend, tooltip, ...).
+    /// Serialized first, so that a typed field set later wins over the same key in here
+    #[serde(flatten)]
+    pub extra: Option<Value>,
+
+    /// Color palette for the series
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Vec<Value>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_style: Option<TextStyle>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<ThemeTitle>,
+}
+
+impl Theme {
+
+    /// Parse a theme from JSON. Accepts both the bare theme object and
+    /// the theme builder configuration file, where the theme is nested under "theme".
+    /// Known keys fill the typed fields, unless they use value types the typed fields don't cover,
+    /// e.g. a numeric `fontWeight` or a string `fontSize`. These and the unknown keys are kept as they are in `extra`
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let mut value: Value = serde_json::from_str(json)?;
+        if value.get("themeName").is_some()
+            && let Some(theme) = value.get_mut("theme").filter(|t| t.is_object()) {
+            value = theme.take();
+        }
+        let Value::Object(mut object) = value else {
+            return Err(serde::de::Error::custom("theme JSON must be an object"));
+        };
+        let mut theme = Self {
+            color: take_typed(&mut object, "color"),
+            background_color: take_typed(&mut object, "backgroundColor"),
+            text_style: take_typed(&mut object, "textStyle"),
+            title: take_typed(&mut object, "title"),
+            ..Default::default()
+        };
+        if !object.is_empty() {
+            theme.extra = Some(Value::Object(object));
+        }
+        Ok(theme)
+    }
+
+    /// Read a theme from a JSON file exported by the ECharts theme builder
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        Self::from_json(&json).map_err(io::Error::from)
+    }
+}
+
+/// Remove the entry from the object if it converts to the typed value and back without loss
+fn take_typed<T: Serialize + DeserializeOwned>(object: &mut Map<String, Value>, key: &str) -> Option<T> {
+    let value = object.get(key)?;
+    let typed: T = serde_json::from_value(value.clone()).ok()?;
+    if serde_json::to_value(&typed).ok()? != *value {
+        return None;
+    }
+    object.remove(key);
+    Some(typed)
+}
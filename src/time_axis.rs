@@ -1,7 +1,5 @@
 use crate::axis_typing::{AxisKindMarker, CategoryAxis, DefaultSerialisation, SerializeFormat, TimeAxis};
-use serde::ser::Error;
 use serde::{Serialize, Serializer};
-use time::macros::format_description;
 use time::{OffsetDateTime, UtcDateTime};
 use crate::impl_default_marker;
 use crate::impl_default_marker_self;
@@ -74,10 +72,7 @@ impl SerializeFormat<time::Time> for time::Time{
     where
         S: Serializer
     {
-        match value.format(format_description!("[hour]:[minute]:[second]")) {
-            Ok(format) => format.serialize(serializer),
-            Err(err) => Err(Error::custom(err.to_string()))
-        }
+        format!("{:02}:{:02}:{:02}", value.hour(), value.minute(), value.second()).serialize(serializer)
     }
 }
 
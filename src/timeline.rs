@@ -0,0 +1,188 @@
+use serde::{Deserialize, Serialize, Serializer};
+use serde::ser::SerializeStruct;
+use crate::axis_typing::AxisKindMarker;
+use crate::common::Size;
+use crate::ids::IdStrategy;
+use crate::options::{EChartOptions, SeriesDataSource, Title};
+use crate::templates::ScriptTemplate;
+
+/// Timeline axis types supported by ECharts
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum TimelineAxisType {
+    Category,
+    Value,
+    Time
+}
+
+/// Timeline component, the player switching between the frames
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Timeline {
+    pub axis_type: TimelineAxisType,
+
+    /// Start playing when the chart is shown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_play: Option<bool>,
+
+    /// Start over after the last frame
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#loop: Option<bool>,
+
+    /// Play backwards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rewind: Option<bool>,
+
+    /// Time each frame is shown in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub play_interval: Option<u32>,
+
+    /// Frame shown first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_index: Option<usize>,
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self {
+            axis_type: TimelineAxisType::Category,
+            auto_play: None,
+            r#loop: None,
+            rewind: None,
+            play_interval: None,
+            current_index: None,
+        }
+    }
+}
+
+/// New data of one series in a frame
+#[derive(Serialize, Debug, Clone)]
+#[serde(bound = "")]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesFrame<X:AxisKindMarker,Y:AxisKindMarker> {
+    #[serde(flatten)]
+    pub data: SeriesDataSource<X,Y>,
+}
+
+/// One step of the timeline. Series are matched with the base option series by position
+#[derive(Debug, Clone)]
+pub struct TimelineFrame<X:AxisKindMarker,Y:AxisKindMarker> {
+    /// Label of the frame on the timeline
+    pub label: String,
+
+    /// Title shown while the frame is active
+    pub title: Option<Title>,
+
+    pub series: Vec<SeriesFrame<X,Y>>,
+}
+
+impl<X:AxisKindMarker,Y:AxisKindMarker> TimelineFrame<X,Y> {
+
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            title: None,
+            series: Vec::new(),
+        }
+    }
+
+    pub fn title(mut self, title: Title) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Add data for the next series of the base option
+    pub fn add_series<TData:Into<SeriesDataSource<X,Y>>>(mut self, data: TData) -> Self {
+        self.series.push(SeriesFrame { data: data.into() });
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(bound = "")]
+struct FrameOption<'a, X:AxisKindMarker,Y:AxisKindMarker> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: &'a Option<Title>,
+    series: &'a Vec<SeriesFrame<X,Y>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TimelineWithLabels<'a> {
+    #[serde(flatten)]
+    timeline: &'a Timeline,
+    data: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(bound = "EChartOptions<X,Y>: Serialize")]
+struct BaseOption<'a, X:AxisKindMarker,Y:AxisKindMarker> {
+    timeline: TimelineWithLabels<'a>,
+    #[serde(flatten)]
+    base: &'a EChartOptions<X,Y>,
+}
+
+/// Chart animated through a sequence of frames.
+/// The base option holds everything shared by the frames (axes, series types, styles),
+/// each frame only replaces the series data
+#[derive(Debug, Clone)]
+pub struct TimelineOptions<X:AxisKindMarker,Y:AxisKindMarker> {
+    pub base: EChartOptions<X,Y>,
+    pub timeline: Timeline,
+    pub frames: Vec<TimelineFrame<X,Y>>,
+}
+
+impl<X:AxisKindMarker,Y:AxisKindMarker> Serialize for TimelineOptions<X,Y>
+where EChartOptions<X,Y>: Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut state = serializer.serialize_struct("TimelineOptions", 2)?;
+        state.serialize_field("baseOption", &BaseOption {
+            timeline: TimelineWithLabels {
+                timeline: &self.timeline,
+                data: self.frames.iter().map(|f| f.label.as_str()).collect(),
+            },
+            base: &self.base,
+        })?;
+        let frames: Vec<FrameOption<X,Y>> = self.frames.iter()
+            .map(|f| FrameOption { title: &f.title, series: &f.series })
+            .collect();
+        state.serialize_field("options", &frames)?;
+        state.end()
+    }
+}
+
+impl<X:AxisKindMarker,Y:AxisKindMarker> TimelineOptions<X,Y>
+where EChartOptions<X,Y>: Serialize {
+
+    pub fn new(base: EChartOptions<X,Y>) -> Self {
+        Self {
+            base,
+            timeline: Timeline::default(),
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn add_frame(mut self, frame: TimelineFrame<X,Y>) -> Self {
+        self.frames.push(frame);
+        self
+    }
+
+    /// Play the frames automatically, switching every interval milliseconds
+    pub fn auto_play(mut self, play_interval: u32, r#loop: bool) -> Self {
+        self.timeline.auto_play = Some(true);
+        self.timeline.play_interval = Some(play_interval);
+        self.timeline.r#loop = Some(r#loop);
+        self
+    }
+
+    pub fn build(self, width: Size, height: Size) -> ScriptTemplate<X,Y> {
+        self.build_with_id_strategy(IdStrategy::Unique, width, height)
+    }
+
+    pub fn build_with_id_strategy(self, ids: IdStrategy, width: Size, height: Size) -> ScriptTemplate<X,Y> {
+        ScriptTemplate::new_timeline(ids.id(&self, &width, &height), width, height, self)
+    }
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "actix")]
+mod common;
+use actix_web::body::to_bytes;
+use actix_web::http::{header, StatusCode};
+use actix_web::test::TestRequest;
+use actix_web::Responder;
+use apache_echarts_wrapper::actix_integration::WithETag;
+use apache_echarts_wrapper::common::Size;
+use apache_echarts_wrapper::options::SeriesType;
+use apache_echarts_wrapper::events::{ChartEvent, EventAction};
+use apache_echarts_wrapper::ids::IdStrategy;
+use apache_echarts_wrapper::templates::{InitOptions, OnePage, Renderer, ScriptTemplate};
+use apache_echarts_wrapper::EChartOptions;
+use crate::common::chart;
+
+#[tokio::test]
+async fn chart_and_page_are_html() {
+    let req = TestRequest::default().to_http_request();
+    let response = chart().build(Size::pixels(800), Size::pixels(600)).respond_to(&req);
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(header::CONTENT_TYPE).unwrap(), "text/html; charset=utf-8");
+    let html = String::from_utf8(to_bytes(response.into_body()).await.unwrap().to_vec()).unwrap();
+    assert!(html.contains("echarts.init(chartDom);"));
+
+    let response = OnePage::new("Charts", "<p>body</p>").respond_to(&req);
+    assert_eq!(response.headers().get(header::CONTENT_TYPE).unwrap(), "text/html; charset=utf-8");
+}
+
+#[tokio::test]
+async fn options_are_json() {
+    let req = TestRequest::default().to_http_request();
+    let response = chart().respond_to(&req);
+    assert_eq!(response.headers().get(header::CONTENT_TYPE).unwrap(), "application/json");
+    let value: serde_json::Value = serde_json::from_slice(&to_bytes(response.into_body()).await.unwrap()).unwrap();
+    assert_eq!(value["series"][0]["data"], serde_json::json!([["a", 1.0], ["b", 2.0]]));
+}
+
+fn build(options: EChartOptions<&'static str, f64>) -> ScriptTemplate<&'static str, f64> {
+    options.build_with_id_strategy(IdStrategy::Fixed("sales"), Size::pixels(800), Size::pixels(600))
+}
+
+#[tokio::test]
+async fn unchanged_chart_is_not_modified() {
+    let req = TestRequest::default().to_http_request();
+    let response = WithETag(build(chart())).respond_to(&req);
+    assert_eq!(response.status(), StatusCode::OK);
+    let etag = response.headers().get(header::ETAG).unwrap().to_str().unwrap().to_string();
+
+    let req = TestRequest::default().insert_header((header::IF_NONE_MATCH, etag.as_str())).to_http_request();
+    let response = WithETag(build(chart())).respond_to(&req);
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers().get(header::ETAG).unwrap(), etag.as_str());
+
+    let changed = chart().add_series(SeriesType::Line, "other".to_string(), vec![("a", 3.0)]);
+    let response = WithETag(build(changed)).respond_to(&req);
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_ne!(response.headers().get(header::ETAG).unwrap(), etag.as_str());
+}
+
+#[tokio::test]
+async fn any_rendered_change_changes_the_tag() {
+    let tag = |template: ScriptTemplate<&'static str, f64>| {
+        let response = WithETag(template).respond_to(&TestRequest::default().to_http_request());
+        response.headers().get(header::ETAG).unwrap().to_str().unwrap().to_string()
+    };
+    let plain = tag(build(chart()));
+    let svg = tag(build(chart()).init_options(InitOptions { renderer: Some(Renderer::Svg), ..Default::default() }));
+    let handler = tag(build(chart()).on(ChartEvent::Click, EventAction::navigate("/orders/{name}")));
+    let other_id = tag(chart().build_with_id_strategy(IdStrategy::Fixed("other"), Size::pixels(800), Size::pixels(600)));
+    assert_ne!(plain, svg);
+    assert_ne!(plain, handler);
+    assert_ne!(plain, other_id);
+}
@@ -0,0 +1,18 @@
+#![cfg(feature = "askama")]
+mod common;
+use apache_echarts_wrapper::common::Size;
+use apache_echarts_wrapper::templates::ScriptTemplate;
+use askama::Template;
+
+#[derive(Template)]
+#[template(source = "<main>{{ chart }}</main>", ext = "html")]
+struct Page {
+    chart: ScriptTemplate<&'static str, f64>,
+}
+
+#[test]
+fn askama_embeds_unescaped() {
+    let html = Page { chart: common::chart().build_htmx("sales", Size::percent(100.0), Size::pixels(400)) }.render().unwrap();
+    assert!(html.starts_with(r#"<main>"#));
+    assert!(html.contains(r#"<div id="sales" style="#));
+}
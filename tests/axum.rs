@@ -0,0 +1,39 @@
+#![cfg(feature = "axum")]
+mod common;
+use apache_echarts_wrapper::common::Size;
+use apache_echarts_wrapper::templates::OnePage;
+use axum::body::to_bytes;
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use crate::common::chart;
+
+async fn body(response: Response) -> String {
+    String::from_utf8(to_bytes(response.into_body(), usize::MAX).await.unwrap().to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn chart_is_html_fragment() {
+    let response = chart().build(Size::pixels(800), Size::pixels(600)).into_response();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "text/html; charset=utf-8");
+    let html = body(response).await;
+    assert!(html.contains("echarts.init(chartDom);"));
+    assert!(!html.contains("<html"));
+}
+
+#[tokio::test]
+async fn page_is_full_document() {
+    let response = OnePage::new("Charts", "<p>body</p>").into_response();
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "text/html; charset=utf-8");
+    let html = body(response).await;
+    assert!(html.contains("<title>Charts</title>"));
+    assert!(html.contains("<p>body</p>"));
+}
+
+#[tokio::test]
+async fn options_are_json() {
+    let response = chart().into_response();
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    let value: serde_json::Value = serde_json::from_str(&body(response).await).unwrap();
+    assert_eq!(value["series"][0]["data"], serde_json::json!([["a", 1.0], ["b", 2.0]]));
+}
@@ -1,10 +1,14 @@
+#![cfg(feature = "chrono_axis")]
 mod common;
 use chrono::Month::{August, February, January, September};
-use chrono::TimeZone;
+use chrono::{DateTime, TimeZone, Utc};
 use sailfish::TemplateSimple;
 use apache_echarts_wrapper::common::Size;
 use apache_echarts_wrapper::EChartOptions;
-use apache_echarts_wrapper::options::SeriesType;
+use apache_echarts_wrapper::live::LiveUpdate;
+use apache_echarts_wrapper::options::{DataZoom, MarkData, MarkLine, Series, SeriesType};
+use apache_echarts_wrapper::templates::DataTable;
+use serde_json::json;
 use crate::common::show_page;
 
 #[cfg(feature = "chrono_axis")]
@@ -46,4 +50,72 @@ fn test_chrono() {
     let mut body =  chart_month.render_once().unwrap();
     body.push_str(&chart_datetime.render_once().unwrap());
     show_page(&body);
-}
\ No newline at end of file
+}
+
+#[test]
+fn data_zoom_window_uses_axis_serialization() {
+    let chart = EChartOptions::<DateTime<Utc>, f64>::default().add_x_data_zoom(
+        DataZoom::slider().range_values(
+            Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2021, 1, 2, 0, 0, 0).unwrap(),
+        ),
+    );
+    let value = serde_json::to_value(&chart).unwrap();
+    assert_eq!(value["dataZoom"][0]["startValue"], json!(1609459200000i64));
+    assert_eq!(value["dataZoom"][0]["endValue"], json!(1609545600000i64));
+}
+
+#[test]
+fn mark_line_at_deploy_time() {
+    let deploy = Utc.with_ymd_and_hms(2021, 1, 2, 12, 0, 0).unwrap();
+    let series = Series::new(
+        "requests".to_string(),
+        SeriesType::Line,
+        vec![(Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap(), 10.0)].into(),
+    )
+    .mark_line(MarkLine::new(vec![
+        MarkData::x(deploy).name("deploy").into(),
+    ]));
+    let chart = EChartOptions::<DateTime<Utc>, f64>::default().add_series_direct(series);
+    let value = serde_json::to_value(&chart).unwrap();
+    assert_eq!(
+        value["series"][0]["markLine"]["data"][0],
+        json!({"xAxis": 1609588800000i64, "name": "deploy"})
+    );
+}
+
+#[test]
+fn data_table_shows_dates_as_iso() {
+    let html = EChartOptions::<DateTime<Utc>, f64>::default()
+        .title_str("requests".to_string())
+        .add_series(
+            SeriesType::Line,
+            "api".to_string(),
+            vec![
+                (Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap(), 10.0),
+                (Utc.with_ymd_and_hms(2021, 1, 1, 12, 30, 0).unwrap(), 12.5),
+            ],
+        )
+        .build(Size::pixels(800), Size::pixels(600))
+        .data_table(DataTable::NoScript)
+        .render_once()
+        .unwrap();
+    let noscript = &html[html.find("<noscript>").unwrap()..html.find("</noscript>").unwrap()];
+    assert!(noscript.contains("<table id="));
+    assert!(noscript.contains("<caption>requests</caption>"));
+    assert!(noscript.contains(r#"<thead><tr><th scope="col">Series</th><th scope="col">x</th><th scope="col">y</th></tr></thead>"#));
+    assert!(noscript.contains("<tr><td>api</td><td>2021-01-01T00:00:00Z</td><td>10</td></tr>"));
+    assert!(noscript.contains("<tr><td>api</td><td>2021-01-01T12:30:00Z</td><td>12.5</td></tr>"));
+}
+
+#[test]
+fn live_update_uses_axis_serialization() {
+    let update = LiveUpdate::<DateTime<Utc>, f64>::shift(
+        "requests",
+        vec![(Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap(), 10.0)],
+    );
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({"type": "shift", "series": "requests", "data": [[1609459200000i64, 10.0]]})
+    );
+}
@@ -1,9 +1,24 @@
+// every test crate uses a part of the helpers
+#![allow(dead_code)]
 use std::net::TcpListener;
 use std::thread;
 use std::time::Duration;
 use sailfish::TemplateSimple;
 use tiny_http::{Header, Server};
 use apache_echarts_wrapper::templates::OnePage;
+use apache_echarts_wrapper::EChartOptions;
+use apache_echarts_wrapper::options::SeriesType;
+
+/// Bar chart with a single series, shared by the rendering and integration tests
+pub fn chart() -> EChartOptions<&'static str, f64> {
+    EChartOptions::<&str, f64>::default()
+        .add_series(SeriesType::Bar, "set".to_string(), vec![("a", 1.0), ("b", 2.0)])
+}
+
+/// The [`chart`] options as JSON, as handed to the template engine filters
+pub fn options_json() -> serde_json::Value {
+    serde_json::to_value(chart()).unwrap()
+}
 
 pub fn show_page(body: &str) {
     // Generate your HTML string here
@@ -0,0 +1,126 @@
+use apache_echarts_wrapper::options::{
+    DataZoom, DataZoomFilterMode, EChartOptions, SaveAsImageFeature, Toolbox, ToolboxFeatures, MarkArea, MarkData, MarkLine, MarkPoint, MarkStatistic,
+    Series, SeriesDataSource, SeriesType, VisualChannels, VisualMap, VisualPiece,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn data_zoom_binds_to_axes() {
+    let chart = EChartOptions::<f64, f64>::default()
+        .add_x_data_zoom(DataZoom::slider().range_values(10.0, 20.0))
+        .add_y_data_zoom(DataZoom::inside().range_percent(0.0, 50.0).filter_mode(DataZoomFilterMode::Empty));
+    let value = serde_json::to_value(&chart).unwrap();
+
+    assert_eq!(
+        value["dataZoom"],
+        json!([
+            { "type": "slider", "xAxisIndex": 0, "startValue": 10.0, "endValue": 20.0 },
+            { "type": "inside", "yAxisIndex": 0, "filterMode": "empty", "start": 0.0, "end": 50.0 }
+        ])
+    );
+}
+
+#[test]
+fn enable_zoom_adds_inside_and_slider() {
+    let chart = EChartOptions::<f64, f64>::default().enable_zoom().enable_zoom();
+    let value = serde_json::to_value(&chart).unwrap();
+
+    assert_eq!(
+        value["dataZoom"],
+        json!([
+            { "type": "inside", "xAxisIndex": 0, "start": 0.0, "end": 100.0 },
+            { "type": "slider", "xAxisIndex": 0, "start": 0.0, "end": 100.0 }
+        ])
+    );
+}
+
+#[test]
+fn visual_maps_serialize_by_type() {
+    let chart = EChartOptions::<f64, f64>::default()
+        .add_visual_map(
+            VisualMap::continuous(0.0, 100.0)
+                .dimension(1)
+                .in_range(VisualChannels::colors(&["#313695", "#a50026"]))
+        )
+        .add_visual_map(
+            VisualMap::piecewise(vec![
+                VisualPiece::range(None, Some(200.0)).label("ok").color("green"),
+                VisualPiece::range(Some(200.0), None).label("slow").color("red"),
+            ])
+            .series_index(vec![1])
+            .out_of_range(VisualChannels::colors(&["#999"]))
+        );
+    let value = serde_json::to_value(&chart).unwrap();
+
+    assert_eq!(
+        value["visualMap"],
+        json!([
+            {
+                "type": "continuous", "min": 0.0, "max": 100.0, "calculable": true, "dimension": 1,
+                "inRange": { "color": ["#313695", "#a50026"] }
+            },
+            {
+                "type": "piecewise",
+                "pieces": [
+                    { "max": 200.0, "label": "ok", "color": "green" },
+                    { "min": 200.0, "label": "slow", "color": "red" }
+                ],
+                "seriesIndex": [1],
+                "outOfRange": { "color": ["#999"] }
+            }
+        ])
+    );
+}
+
+#[test]
+fn series_annotations_use_statistics_and_axis_values() {
+    let series = Series::new("latency".to_string(), SeriesType::Line, SeriesDataSource::from_pairs(vec![(1.0, 120.0), (2.0, 340.0)]))
+        .mark_point(MarkPoint::new(vec![MarkData::statistic(MarkStatistic::Max).name("peak")]))
+        .mark_line(MarkLine::new(vec![
+            MarkData::statistic(MarkStatistic::Average).into(),
+            MarkData::y(300.0).name("SLO").into(),
+            (MarkData::coord(1.0, 100.0), MarkData::coord(2.0, 200.0)).into(),
+        ]))
+        .mark_area(MarkArea::new(vec![(MarkData::x(1.5).name("incident"), MarkData::x(1.8))]));
+    let chart = EChartOptions::<f64, f64>::default().add_series_direct(series);
+    let value = serde_json::to_value(&chart).unwrap();
+
+    assert_eq!(value["series"][0]["markPoint"], json!({ "data": [{ "type": "max", "name": "peak" }] }));
+    assert_eq!(
+        value["series"][0]["markLine"],
+        json!({ "data": [
+            { "type": "average" },
+            { "yAxis": 300.0, "name": "SLO" },
+            [{ "coord": [1.0, 100.0] }, { "coord": [2.0, 200.0] }]
+        ] })
+    );
+    assert_eq!(
+        value["series"][0]["markArea"],
+        json!({ "data": [[{ "xAxis": 1.5, "name": "incident" }, { "xAxis": 1.8 }]] })
+    );
+}
+
+#[test]
+fn enable_toolbox_keeps_configured_features() {
+    let mut chart = EChartOptions::<f64, f64>::default();
+    chart.toolbox = Some(Toolbox {
+        feature: ToolboxFeatures {
+            save_as_image: Some(SaveAsImageFeature { name: Some("latency".to_string()), ..Default::default() }),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let value = serde_json::to_value(chart.enable_toolbox()).unwrap();
+
+    assert_eq!(
+        value["toolbox"],
+        json!({ "feature": {
+            "saveAsImage": { "name": "latency" },
+            "dataView": { "readOnly": true },
+            "dataZoom": { "yAxisIndex": "none" },
+            "magicType": { "type": ["line", "bar"] },
+            "restore": {}
+        } })
+    );
+}
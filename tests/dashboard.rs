@@ -0,0 +1,61 @@
+use apache_echarts_wrapper::common::Size;
+use apache_echarts_wrapper::dashboard::{ChartGroup, Dashboard, Panel};
+use apache_echarts_wrapper::options::SeriesType;
+use apache_echarts_wrapper::EChartOptions;
+use sailfish::TemplateSimple;
+
+#[test]
+fn dashboard_places_charts_of_different_types() {
+    let revenue = EChartOptions::<&str, f64>::default()
+        .add_series(SeriesType::Bar, "revenue".to_string(), vec![("Jan", 10.0), ("Feb", 12.0)])
+        .build_htmx("revenue", Size::percent(100.0), Size::pixels(300));
+    let latency = EChartOptions::<f64, f64>::default()
+        .add_series(SeriesType::Line, "p99".to_string(), vec![(1.0, 120.0), (2.0, 95.0)])
+        .build_htmx("latency", Size::percent(100.0), Size::pixels(300));
+
+    let html = Dashboard::new("Operations")
+        .columns(3)
+        .head_asset(r#"<script src="https://unpkg.com/htmx.org@2.0.4"></script>"#)
+        .add_panel(Panel::new(revenue).title("Revenue").span(2, 1))
+        .add_panel(Panel::new(latency).title("Latency").at(3, 1))
+        .connect()
+        .render_once().unwrap();
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert_eq!(html.matches("echarts.min.js").count(), 1);
+    assert!(html.contains(r#"<script src="https://unpkg.com/htmx.org@2.0.4"></script>"#));
+    assert!(html.contains("grid-template-columns: repeat(3, minmax(0, 1fr)); gap: 16px;"));
+    assert!(html.contains(r#"<section style="grid-column: span 2; grid-row: span 1;">"#));
+    assert!(html.contains(r#"<section style="grid-column: 3 / span 1; grid-row: 1 / span 1;">"#));
+    assert!(html.contains("<h2>Revenue</h2>"));
+    assert!(html.contains(r#"<div id="latency" style="width: 100%;height:300px;"></div>"#));
+    assert_eq!(html.matches(r#"myChart.group = "dashboard";"#).count(), 2);
+}
+
+#[test]
+fn dashboard_is_not_connected_by_default() {
+    let chart = EChartOptions::<&str, f64>::default()
+        .add_series(SeriesType::Bar, "set".to_string(), vec![("a", 1.0)])
+        .build(Size::percent(100.0), Size::pixels(300));
+    let html = Dashboard::new("Single").add_chart("Set", chart).render_once().unwrap();
+    assert!(html.contains("<title>Single</title>"));
+    assert!(!html.contains("echarts.connect"));
+}
+
+#[test]
+fn grouped_charts_connect_once_per_page() {
+    let group = ChartGroup::new("traffic");
+    let fragments: Vec<String> = ["requests", "errors"].iter().map(|name| {
+        group.add(
+            EChartOptions::<f64, f64>::default()
+                .add_series(SeriesType::Line, name.to_string(), vec![(1.0, 3.0), (2.0, 4.0)])
+                .build_htmx(name, Size::percent(100.0), Size::pixels(300))
+        ).render_once().unwrap()
+    }).collect();
+
+    for html in &fragments {
+        assert!(html.contains(r#"myChart.group = "traffic";"#));
+        assert!(html.contains("if (!window.echartsConnectedGroups[myChart.group]) {"));
+        assert!(html.contains("echarts.connect(myChart.group);"));
+    }
+}
@@ -0,0 +1,126 @@
+use apache_echarts_wrapper::diff::{diff, diff_values};
+use apache_echarts_wrapper::live::LiveUpdate;
+use apache_echarts_wrapper::options::SeriesType;
+use apache_echarts_wrapper::EChartOptions;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+fn chart(second: Vec<(&'static str, f64)>) -> EChartOptions<&'static str, f64> {
+    EChartOptions::<&str, f64>::default()
+        .title_str("Load".to_string())
+        .add_series(SeriesType::Line, "first".to_string(), vec![("a", 1.0), ("b", 2.0)])
+        .add_series(SeriesType::Line, "second".to_string(), second)
+}
+
+#[test]
+fn unchanged_options_give_empty_patch() {
+    let patch = diff(&chart(vec![("a", 3.0)]), &chart(vec![("a", 3.0)])).unwrap();
+    assert!(patch.is_empty());
+    assert_eq!(patch.option, json!({}));
+}
+
+#[test]
+fn changed_series_is_matched_by_name() {
+    let patch = diff(&chart(vec![("a", 3.0)]), &chart(vec![("a", 3.0), ("b", 4.0)])).unwrap();
+    assert_eq!(patch.option, json!({
+        "series": [
+            { "name": "first" },
+            { "name": "second", "data": [["a", 3.0], ["b", 4.0]] }
+        ]
+    }));
+    assert!(patch.replace_merge.is_empty());
+
+    let update = LiveUpdate::<&str, f64>::patch(patch);
+    assert_eq!(serde_json::to_value(&update).unwrap()["option"]["series"][1]["name"], json!("second"));
+}
+
+#[test]
+fn objects_are_merged_key_by_key() {
+    let previous = json!({
+        "title": { "text": "Load", "left": "center" },
+        "backgroundColor": "#fff",
+        "color": ["#111", "#222"],
+        "textStyle": { "fontSize": 12, "color": "#333" }
+    });
+    let current = json!({
+        "title": { "text": "Load (5m)", "left": "center" },
+        "color": ["#111", "#444"],
+        "textStyle": { "fontSize": 14, "color": "#333" }
+    });
+    assert_eq!(diff_values(&previous, &current).option, json!({
+        "title": [{ "text": "Load (5m)" }],
+        "color": ["#111", "#444"],
+        "textStyle": { "fontSize": 14 },
+        "backgroundColor": null
+    }));
+}
+
+#[test]
+fn components_are_matched_by_id_then_position() {
+    let previous = json!({
+        "xAxis": [{ "type": "time" }, { "type": "value", "min": 0 }],
+        "dataZoom": [{ "id": "slider", "start": 0, "end": 100 }, { "id": "inside", "start": 0, "end": 100 }]
+    });
+    let current = json!({
+        "xAxis": [{ "type": "time" }, { "type": "value", "min": 10 }],
+        "dataZoom": [{ "id": "slider", "start": 0, "end": 100 }, { "id": "inside", "start": 50, "end": 100 }]
+    });
+    assert_eq!(diff_values(&previous, &current).option, json!({
+        "xAxis": [{}, { "min": 10 }],
+        "dataZoom": [{ "id": "slider" }, { "id": "inside", "start": 50 }]
+    }));
+}
+
+#[test]
+fn replaced_components_use_replace_merge() {
+    let previous = json!({ "series": [{ "id": "a", "data": [1] }, { "id": "b", "data": [2] }] });
+    let current = json!({ "series": [{ "id": "b", "data": [2] }, { "id": "c", "data": [3] }] });
+    let patch = diff_values(&previous, &current);
+    assert_eq!(patch.option, current);
+    assert_eq!(patch.replace_merge, vec!["series".to_string()]);
+}
+
+#[test]
+fn component_inserted_in_the_middle_uses_replace_merge() {
+    let previous = json!({ "series": [{ "id": "a", "data": [1] }, { "id": "b", "data": [2] }] });
+    let current = json!({ "series": [{ "id": "a", "data": [1] }, { "id": "c", "data": [3] }, { "id": "b", "data": [2] }] });
+    let patch = diff_values(&previous, &current);
+    assert_eq!(patch.option, current);
+    assert_eq!(patch.replace_merge, vec!["series".to_string()]);
+}
+
+#[test]
+fn component_inserted_at_the_front_uses_replace_merge() {
+    let previous = json!({ "series": [{ "id": "a", "data": [1] }, { "id": "b", "data": [2] }] });
+    let current = json!({ "series": [{ "id": "c", "data": [3] }, { "id": "a", "data": [1] }, { "id": "b", "data": [2] }] });
+    let patch = diff_values(&previous, &current);
+    assert_eq!(patch.option, current);
+    assert_eq!(patch.replace_merge, vec!["series".to_string()]);
+}
+
+#[test]
+fn appended_components_are_merged() {
+    let previous = json!({ "series": [{ "id": "a", "data": [1] }, { "id": "b", "data": [2] }] });
+    let appended = json!({ "series": [{ "id": "a", "data": [1] }, { "id": "b", "data": [2] }, { "id": "c", "data": [3] }] });
+    let patch = diff_values(&previous, &appended);
+    assert_eq!(patch.option, json!({ "series": [{ "id": "a" }, { "id": "b" }, { "id": "c", "data": [3] }] }));
+    assert!(patch.replace_merge.is_empty());
+}
+
+#[test]
+fn reordered_components_use_replace_merge() {
+    let previous = json!({ "series": [{ "id": "a", "data": [1] }, { "id": "b", "data": [2] }] });
+    let current = json!({ "series": [{ "id": "b", "data": [2] }, { "id": "a", "data": [1] }] });
+    let patch = diff_values(&previous, &current);
+    assert_eq!(patch.option, current);
+    assert_eq!(patch.replace_merge, vec!["series".to_string()]);
+}
+
+#[test]
+fn removed_components_use_replace_merge() {
+    let previous = json!({ "series": [{ "name": "a", "data": [1] }, { "name": "b", "data": [2] }], "legend": {} });
+    let current = json!({ "series": [{ "name": "b", "data": [2] }] });
+    let patch = diff_values(&previous, &current);
+    assert_eq!(patch.option, json!({ "series": [{ "name": "b", "data": [2] }], "legend": [] }));
+    assert_eq!(patch.replace_merge, vec!["series".to_string(), "legend".to_string()]);
+}
@@ -0,0 +1,86 @@
+use apache_echarts_wrapper::common::Size;
+use apache_echarts_wrapper::diff::diff;
+use apache_echarts_wrapper::ids::{IdCounter, IdStrategy};
+use apache_echarts_wrapper::options::{Axis, DatasetComponent, Grid, Legend, Series, SeriesDataSource, SeriesType, Title};
+use apache_echarts_wrapper::EChartOptions;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+fn chart(values: Vec<(&'static str, f64)>) -> EChartOptions<&'static str, f64> {
+    EChartOptions::<&str, f64>::default()
+        .title_str("Sales".to_string())
+        .add_series(SeriesType::Bar, "Sales 2024".to_string(), values)
+        .add_series(SeriesType::Line, "Sales 2024".to_string(), vec![("a", 1.0)])
+        .add_series_direct(Series::new(String::new(), SeriesType::Line, vec![("a", 2.0)].into()))
+}
+
+#[test]
+fn ids_are_serialized_when_set() {
+    let options = EChartOptions::<&str, f64>::new(Axis::new_named("Month".to_string()).id("months"), Axis::default())
+        .add_series_direct(Series::new("Sales".to_string(), SeriesType::Bar, vec![("a", 1.0)].into()).id("sales"));
+    let json = serde_json::to_value(&options).unwrap();
+    assert_eq!(json["xAxis"]["id"], json!("months"));
+    assert!(json["yAxis"].get("id").is_none());
+    assert_eq!(json["series"][0]["id"], json!("sales"));
+
+    assert_eq!(serde_json::to_value(Title::new("Sales").id("title")).unwrap()["id"], json!("title"));
+    assert_eq!(serde_json::to_value(Legend::default().id("legend")).unwrap(), json!({ "id": "legend" }));
+    assert_eq!(serde_json::to_value(Grid::default().id("main")).unwrap(), json!({ "id": "main" }));
+    let dataset = DatasetComponent::<&str, f64>::src(vec![("a", 1.0)]).id("raw");
+    assert_eq!(serde_json::to_value(dataset).unwrap(), json!({ "id": "raw", "source": [["a", 1.0]] }));
+}
+
+#[test]
+fn auto_ids_are_derived_from_series_names() {
+    let options = chart(vec![("a", 3.0)])
+        .add_series_direct(Series::new("custom".to_string(), SeriesType::Line, SeriesDataSource::DatasetIndex(0)).id("kept"))
+        .auto_ids();
+    let json = serde_json::to_value(&options).unwrap();
+    let ids: Vec<_> = json["series"].as_array().unwrap().iter().map(|s| s["id"].clone()).collect();
+    assert_eq!(ids, vec![json!("sales-2024"), json!("sales-2024-2"), json!("series-2"), json!("kept")]);
+}
+
+#[test]
+fn updates_are_matched_by_id() {
+    let patch = diff(&chart(vec![("a", 3.0)]).auto_ids(), &chart(vec![("a", 4.0)]).auto_ids()).unwrap();
+    assert_eq!(patch.option, json!({
+        "series": [{ "id": "sales-2024", "data": [["a", 4.0]] }]
+    }));
+}
+
+#[test]
+fn identical_charts_get_distinct_ids() {
+    let first = chart(vec![("a", 3.0)]).build(Size::percent(100.0), Size::pixels(400));
+    let second = chart(vec![("a", 3.0)]).build(Size::percent(100.0), Size::pixels(400));
+    assert_ne!(first.chart_target_id(), second.chart_target_id());
+    let number = |id: &str| id.strip_prefix("echarts-").unwrap().parse::<u64>().unwrap();
+    assert!(number(first.chart_target_id()) < number(second.chart_target_id()));
+    let html = format!("{first}{second}");
+    assert_eq!(html.matches(&format!(r#"<div id="{}""#, first.chart_target_id())).count(), 1);
+}
+
+#[test]
+fn content_hash_id_is_deterministic() {
+    let hashed = |values| chart(values).build_with_id_strategy(IdStrategy::ContentHash, Size::percent(100.0), Size::pixels(400));
+    let first = hashed(vec![("a", 3.0)]);
+    let second = hashed(vec![("a", 3.0)]);
+    let other = hashed(vec![("a", 4.0)]);
+    assert_eq!(first.chart_target_id(), second.chart_target_id());
+    assert_ne!(first.chart_target_id(), other.chart_target_id());
+    assert!(first.chart_target_id().starts_with("chart-"));
+    assert_eq!(first.to_string(), second.to_string());
+}
+
+#[test]
+fn id_strategies() {
+    let ids = IdCounter::seeded("page", 1);
+    let first = chart(vec![("a", 3.0)]).build_with_id_strategy(IdStrategy::Counter(&ids), Size::percent(100.0), Size::pixels(400));
+    let second = chart(vec![("a", 3.0)]).build_with_id_strategy(IdStrategy::Counter(&ids), Size::percent(100.0), Size::pixels(400));
+    assert_eq!(first.chart_target_id(), "page-1");
+    assert_eq!(second.chart_target_id(), "page-2");
+    ids.reset(1);
+    assert_eq!(ids.next_id(), "page-1");
+
+    let fixed = chart(vec![("a", 3.0)]).build_with_id_strategy(IdStrategy::Fixed("sales"), Size::percent(100.0), Size::pixels(400));
+    assert_eq!(fixed.chart_target_id(), "sales");
+}
@@ -0,0 +1,51 @@
+use apache_echarts_wrapper::common::Size;
+use apache_echarts_wrapper::live::{LiveSource, LiveUpdate};
+use apache_echarts_wrapper::options::SeriesType;
+use apache_echarts_wrapper::EChartOptions;
+use pretty_assertions::assert_eq;
+use sailfish::TemplateSimple;
+use serde_json::json;
+
+#[test]
+fn update_messages_are_tagged_json() {
+    let append = LiveUpdate::<f64, f64>::append("cpu", vec![(3.0, 0.5), (4.0, 0.7)]);
+    assert_eq!(serde_json::to_value(&append).unwrap(), json!({
+        "type": "append", "series": "cpu", "data": [[3.0, 0.5], [4.0, 0.7]]
+    }));
+
+    let shift = LiveUpdate::<&str, f64>::shift("queue", vec![("12:01", 5.0)]);
+    assert_eq!(shift.to_sse_event().unwrap(), "data: {\"type\":\"shift\",\"series\":\"queue\",\"data\":[[\"12:01\",5.0]]}\n\n");
+
+    let replace = LiveUpdate::<&str, f64>::replace_dataset(1, vec![("a", 1.0)]);
+    assert_eq!(serde_json::to_value(&replace).unwrap(), json!({
+        "type": "replaceDataset", "index": 1, "source": [["a", 1.0]]
+    }));
+
+    let merge = LiveUpdate::<f64, f64>::merge(&json!({"title": {"text": "Load"}})).unwrap();
+    assert_eq!(merge.to_json().unwrap(), r#"{"type":"merge","option":{"title":{"text":"Load"}}}"#);
+
+    let append_data = LiveUpdate::<f64, f64>::append_data("points", vec![(1.0, 2.0)]);
+    assert_eq!(serde_json::to_value(&append_data).unwrap()["type"], json!("appendData"));
+}
+
+#[test]
+fn live_chart_subscribes_to_source() {
+    let chart = || EChartOptions::<f64, f64>::default()
+        .add_series(SeriesType::Line, "cpu".to_string(), vec![(1.0, 0.2)]);
+
+    let html = chart().build(Size::percent(100.0), Size::pixels(300))
+        .live(LiveSource::sse("/metrics/cpu"))
+        .render_once().unwrap();
+    assert!(html.contains(r#"connection = new EventSource("/metrics/cpu");"#));
+    assert!(html.contains("dom.addEventListener('htmx:beforeCleanupElement', chart.__wrapperLive.close, {once: true});"));
+    assert!(!html.contains("WebSocket"));
+
+    let html = chart().build(Size::percent(100.0), Size::pixels(300))
+        .live(LiveSource::websocket("/ws/cpu"))
+        .render_once().unwrap();
+    assert!(html.contains(r#"var url = new URL("/ws/cpu", window.location.href);"#));
+    assert!(html.contains("setTimeout(connect, 1000)"));
+
+    let html = chart().build(Size::percent(100.0), Size::pixels(300)).render_once().unwrap();
+    assert!(!html.contains("__wrapperLive"));
+}
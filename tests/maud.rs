@@ -0,0 +1,12 @@
+#![cfg(feature = "maud")]
+mod common;
+use apache_echarts_wrapper::common::Size;
+use maud::html;
+
+#[test]
+fn maud_embeds_unescaped() {
+    let chart = common::chart().build_htmx("sales", Size::percent(100.0), Size::pixels(400));
+    let html = html! { main { (chart) } }.into_string();
+    assert!(html.starts_with(r#"<main>"#));
+    assert!(html.contains(r#"<div id="sales" style="#));
+}
@@ -0,0 +1,19 @@
+#![cfg(feature = "minijinja")]
+mod common;
+use apache_echarts_wrapper::minijinja_integration::register;
+use minijinja::{context, Environment};
+use crate::common::options_json;
+
+#[test]
+fn minijinja_filter_renders_options() {
+    let mut env = Environment::new();
+    register(&mut env);
+    env.add_template("page.html", r#"<main>{{ options | echarts_chart(height="300px", id="sales") }}</main>"#).unwrap();
+    let html = env.get_template("page.html").unwrap()
+        .render(context! { options => options_json() }).unwrap();
+    assert!(html.contains(r#"<div id="sales" style="width: 100%;height:300px;"></div>"#));
+    assert!(html.contains(r#""data":[["a",1.0],["b",2.0]]"#));
+
+    let error = env.render_str(r#"{{ options | echarts_chart(width="wide") }}"#, context! { options => options_json() });
+    assert!(error.is_err());
+}
@@ -0,0 +1,243 @@
+mod common;
+use apache_echarts_wrapper::common::{JsFunction, Size};
+use apache_echarts_wrapper::events::{BrushSelected, BrushSelectedHandler, ChartEvent, EventAction};
+use apache_echarts_wrapper::options::{Brush, BrushToolType, EChartOptions, Label, NamedValuePair, Series, SeriesDataSource, SeriesType};
+use apache_echarts_wrapper::live::LiveSource;
+use apache_echarts_wrapper::templates::{AutoResize, DataTable, InitOptions, Locale, Renderer};
+use apache_echarts_wrapper::theme::ChartTheme;
+use sailfish::TemplateSimple;
+use crate::common::chart;
+
+#[test]
+fn init_options_are_third_init_argument() {
+    let html = chart()
+        .build_with_init_options(Size::percent(100.0), Size::pixels(400), InitOptions {
+            renderer: Some(Renderer::Svg),
+            device_pixel_ratio: Some(2.0),
+            locale: Some(Locale::De),
+            ..Default::default()
+        })
+        .render_once().unwrap();
+    assert!(html.contains(r#"echarts.init(chartDom, null, {"renderer":"svg","devicePixelRatio":2.0,"locale":"DE"});"#));
+
+    let html = chart().build(Size::percent(100.0), Size::pixels(400)).render_once().unwrap();
+    assert!(html.contains("echarts.init(chartDom);"));
+}
+
+#[test]
+fn auto_resize_is_opt_in() {
+    let html = chart()
+        .build(Size::percent(100.0), Size::percent(50.0))
+        .auto_resize(AutoResize { debounce_ms: 250, animation_duration_ms: Some(300) })
+        .render_once().unwrap();
+    assert!(html.contains("new ResizeObserver(resize)"));
+    assert!(html.contains("chart.resize({animation: {duration: 300}});\n            }, 250);"));
+    assert!(html.contains("htmx:beforeCleanupElement"));
+
+    let html = chart().build(Size::percent(100.0), Size::percent(50.0)).render_once().unwrap();
+    assert!(!html.contains("ResizeObserver"));
+}
+
+#[test]
+fn brush_selection_is_forwarded() {
+    let html = chart()
+        .brush(Brush::new(vec![BrushToolType::Rect, BrushToolType::Clear]))
+        .build(Size::percent(100.0), Size::pixels(400))
+        .on_brush_selected(BrushSelectedHandler::post_into("/orders/selection", "details"))
+        .render_once().unwrap();
+    assert!(html.contains(r#""brush":{"toolbox":["rect","clear"],"brushLink":"all"}"#));
+    assert!(html.contains("chart.on('brushSelected'"));
+    assert!(html.contains(r#"fetch("/orders/selection", {method: 'POST'"#));
+    assert!(html.contains(r#"document.getElementById("details")"#));
+
+    let html = chart()
+        .build(Size::percent(100.0), Size::pixels(400))
+        .on_brush_selected(BrushSelectedHandler::callback("onSelection"))
+        .render_once().unwrap();
+    assert!(html.contains(r#"window["onSelection"](selection, chart);"#));
+    assert!(!html.contains("fetch("));
+}
+
+#[test]
+fn brush_selection_payload_deserializes() {
+    let selection: BrushSelected = serde_json::from_str(r#"{"batch": [{
+        "brushId": "b1",
+        "areas": [{"brushType": "lineX", "range": [10, 200], "coordRange": [1.5, 3.0]}],
+        "selected": [{"seriesIndex": 0, "seriesName": "set", "dataIndex": [1, 2]}]
+    }]}"#).unwrap();
+    assert_eq!(selection.batch[0].selected[0].data_index, vec![1, 2]);
+    assert_eq!(selection.batch[0].areas[0].brush_type, "lineX");
+}
+
+#[test]
+fn js_functions_are_emitted_as_code() {
+    let mut options = chart();
+    options.tooltip.as_mut().unwrap().formatter = Some(JsFunction::new("function (p) { return p.name + \": \" + p.value[1]; }").into());
+    let series = Series::new(
+        "labelled".to_string(),
+        SeriesType::Line,
+        vec![("a", 1.0)].into()
+    ).label(Label { formatter: Some("{b}: \"{c}\"".into()), ..Default::default() });
+    options.y_axis.extra = Some(serde_json::json!({ "axisLabel": { "formatter": JsFunction::new("v => v + ' ms'") } }));
+    let html = options.add_series_direct(series)
+        .build(Size::percent(100.0), Size::pixels(400))
+        .render_once().unwrap();
+
+    assert!(html.contains(r#""formatter":function (p) { return p.name + ": " + p.value[1]; },"#));
+    assert!(html.contains(r#""formatter":v => v + ' ms'"#));
+    assert!(html.contains(r#""formatter":"{b}: \"{c}\"""#));
+}
+
+#[test]
+fn data_strings_are_never_emitted_as_code() {
+    let forged = "\u{1}js:0000000000000000:alert(1)";
+    let mut options = EChartOptions::<&str, f64>::default()
+        .add_series(SeriesType::Bar, forged.to_string(), vec![("</script><script>alert(2)</script>", 1.0)]);
+    let tagged = serde_json::to_value(JsFunction::new("p => p.name")).unwrap();
+    options.extra = Some(serde_json::json!({ "graphic": { tagged.as_str().unwrap(): 1 }, "label": { "formatter": tagged } }));
+    let html = options.build(Size::percent(100.0), Size::pixels(400)).render_once().unwrap();
+
+    assert!(html.contains(r#""name":"\u0001js:0000000000000000:alert(1)""#));
+    assert!(html.contains(r#"["<\/script><script>alert(2)<\/script>",1.0]"#));
+    assert!(!html.contains("</script><script>"));
+    assert!(html.contains(r#""graphic":{"\u0001js:"#));
+    assert!(html.contains(r#""formatter":p => p.name"#));
+}
+
+#[test]
+fn event_handlers_are_registered() {
+    let html = chart()
+        .build(Size::percent(100.0), Size::pixels(400))
+        .on(ChartEvent::Click, EventAction::navigate("/orders/{name}"))
+        .on(ChartEvent::DblClick, EventAction::htmx_get("/orders/{name}/details", "#details"))
+        .on(ChartEvent::LegendSelectChanged, EventAction::Function(JsFunction::new("function (p) { console.log(p.selected); }")))
+        .render_once().unwrap();
+
+    assert!(html.contains(r#"chart.on("click", function (params) {
+            window.location.href = fill("/orders/{name}", params);"#));
+    assert!(html.contains(r##"htmx.ajax('GET', fill("/orders/{name}/details", params), {
+                target: "#details",
+                swap: "innerHTML","##));
+    assert!(html.contains(r#"chart.on("legendselectchanged", function (p) { console.log(p.selected); });"#));
+}
+
+#[test]
+fn generated_description_goes_to_aria_and_hidden_text() {
+    let options = EChartOptions::<&str, f64>::default()
+        .title_str("Sales".to_string())
+        .add_series(SeriesType::Line, "north".to_string(), vec![("Jan", 3.0), ("Feb", 1.0), ("Mar", 7.5)])
+        .add_series(SeriesType::Line, "south".to_string(), vec![("Jan", 4.0), ("Feb", 4.0), ("Mar", 2.0)])
+        .enable_aria()
+        .describe_data();
+    let description = "Sales. Chart with 2 series: north, south. \
+        north: 3 values, minimum 1 at Feb, maximum 7.5 at Mar, increasing from 3 to 7.5. \
+        south: 3 values, minimum 2 at Mar, maximum 4 at Jan, decreasing from 4 to 2.";
+    let value = serde_json::to_value(&options).unwrap();
+    assert_eq!(value["aria"], serde_json::json!({
+        "enabled": true,
+        "label": { "enabled": true, "description": description },
+        "decal": { "show": true }
+    }));
+
+    let template = options.build(Size::percent(100.0), Size::pixels(400));
+    let id = template.chart_target_id().to_string();
+    let html = template.render_once().unwrap();
+    assert!(html.contains(&format!(r#"<div id="{id}" style="width: 100%;height:400px;" aria-describedby="{id}-description"></div>"#)));
+    assert!(html.contains(&format!("-description\" style=\"position:absolute;width:1px;height:1px;padding:0;margin:-1px;overflow:hidden;clip:rect(0,0,0,0);white-space:nowrap;border:0;\">{description}</p>")));
+
+    let html = chart().enable_aria().build(Size::percent(100.0), Size::pixels(400)).render_once().unwrap();
+    assert!(!html.contains("-description"));
+    assert!(!html.contains("aria-describedby"));
+}
+
+#[test]
+fn hidden_data_table_lists_named_points() {
+    let html = EChartOptions::<&str, f64>::default()
+        .add_series(SeriesType::Bar, "set".to_string(), SeriesDataSource::from_named_value_pairs(vec![
+            NamedValuePair::new("a", 1.5, "first <1>".to_string()),
+        ]))
+        .build(Size::percent(100.0), Size::pixels(400))
+        .data_table(DataTable::Hidden)
+        .render_once().unwrap();
+    assert!(html.contains(r#"<th scope="col">Name</th>"#));
+    assert!(html.contains("<tr><td>set</td><td>a</td><td>1.5</td><td>first &lt;1&gt;</td></tr>"));
+    assert!(html.contains("</table>\n\n</div>"));
+    assert!(!html.contains("<details>"));
+}
+
+#[test]
+fn htmx_mode_reuses_and_disposes_instance() {
+    let html = chart()
+        .build_htmx("sales", Size::percent(100.0), Size::pixels(400))
+        .on(ChartEvent::Click, EventAction::navigate("/sales/{name}"))
+        .render_once().unwrap();
+    assert!(html.contains(r#"<div id="sales" style="#));
+    assert!(html.contains(r#"<div id="sales-update" hidden></div>"#));
+    assert!(html.contains("var myChart = echarts.getInstanceByDom(chartDom) || echarts.init(chartDom);"));
+    assert!(html.contains("myChart.setOption(option, {notMerge: true});"));
+    assert!(html.contains("dom.addEventListener('htmx:beforeCleanupElement'"));
+    assert!(html.contains(r#"myChart.__wrapperEvents = ["click"];"#));
+
+    let html = chart().build(Size::percent(100.0), Size::pixels(400)).render_once().unwrap();
+    assert!(!html.contains("getInstanceByDom"));
+    assert!(!html.contains("-update"));
+}
+
+#[test]
+fn data_update_is_out_of_band_fragment() {
+    let html = EChartOptions::<&str, f64>::default()
+        .add_series(SeriesType::Bar, "set".to_string(), vec![("a", 3.0)])
+        .build_data_update("sales")
+        .render_once().unwrap();
+    assert!(html.starts_with(r#"<div id="sales-update" hx-swap-oob="true" hidden>"#));
+    assert!(html.contains(r#"chart && chart.setOption({"series":[{"name":"set","data":[["a",3.0]]}]});"#));
+    assert!(html.contains(r#"})(document.getElementById("sales"));"#));
+}
+
+#[test]
+fn container_id_is_escaped_in_script() {
+    let html = chart().build_htmx(r#"it's "a" \ & b"#, Size::percent(100.0), Size::pixels(400)).render_once().unwrap();
+    assert!(html.contains(r#"<div id="it&#039;s &quot;a&quot; \ &amp; b" style="#));
+    assert!(html.contains(r#"var chartDom = document.getElementById("it's \"a\" \\ & b");"#));
+
+    let id = "x</script><b>pwn</b>";
+    let html = chart().build_htmx(id, Size::percent(100.0), Size::pixels(400)).render_once().unwrap();
+    assert_eq!(html.matches("</script>").count(), 1);
+    assert!(html.contains(r#"<div id="x&lt;/script&gt;&lt;b&gt;pwn&lt;/b&gt;" style="#));
+    assert!(html.contains(r#"var chartDom = document.getElementById("x<\/script><b>pwn<\/b>");"#));
+    let update = chart().build_data_update(id).render_once().unwrap();
+    assert_eq!(update.matches("</script>").count(), 1);
+    assert!(update.contains(r#"})(document.getElementById("x<\/script><b>pwn<\/b>"));"#));
+}
+
+#[test]
+fn script_values_cannot_close_the_script() {
+    let evil = "/o/</script><b>pwn</b>{name}";
+    let build = || chart().build(Size::percent(100.0), Size::pixels(400));
+    let templates = [
+        build().on(ChartEvent::Click, EventAction::navigate(evil)),
+        build().on(ChartEvent::Click, EventAction::htmx_get(evil, evil)),
+        build().on_brush_selected(BrushSelectedHandler::Post { url: evil.to_string(), target: Some(evil.to_string()) }),
+        build().on_brush_selected(BrushSelectedHandler::Callback(evil.to_string())),
+        build().live(LiveSource::Sse(evil.to_string())),
+        build().live(LiveSource::WebSocket(evil.to_string())),
+        build().group(evil),
+        build().theme(ChartTheme::Named(evil.to_string())),
+        chart().build_with_init_options(Size::percent(100.0), Size::pixels(400), InitOptions {
+            locale: Some(Locale::Custom(evil.to_string())),
+            ..Default::default()
+        }),
+    ];
+    for template in templates {
+        let html = template.render_once().unwrap();
+        assert_eq!(html.matches("</script>").count(), 1, "{html}");
+        assert!(html.contains(r#"/o/<\/script><b>pwn<\/b>{name}"#), "{html}");
+    }
+}
+
+#[test]
+fn display_renders_fragment() {
+    let html = chart().build_htmx("sales", Size::percent(100.0), Size::pixels(400)).to_string();
+    assert!(html.contains(r#"<div id="sales" style="width: 100%;height:400px;"></div>"#));
+    assert!(html.contains(r#""series":[{"type":"bar","name":"set","data":[["a",1.0],["b",2.0]]}]"#));
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "testing")]
+mod common;
+use std::fs;
+use apache_echarts_wrapper::common::{JsFunction, Size};
+use apache_echarts_wrapper::ids::IdStrategy;
+use apache_echarts_wrapper::testing::{assert_html_snapshot, assert_json_snapshot, assert_snapshot_with};
+use crate::common::chart;
+
+#[test]
+fn options_and_html_match_snapshots() {
+    assert_json_snapshot("tests/snapshots/sales.json", &chart());
+    assert_html_snapshot("tests/snapshots/sales.html", chart().build_with_id_strategy(IdStrategy::ContentHash, Size::percent(100.0), Size::pixels(400)));
+}
+
+#[test]
+fn js_functions_are_snapshotted_as_code() {
+    let mut options = chart();
+    options.tooltip.as_mut().unwrap().formatter = Some(JsFunction::new("p => p.name + ': ' + p.value[1]").into());
+    assert_json_snapshot("tests/snapshots/formatter.json", &options);
+    assert_html_snapshot("tests/snapshots/formatter.html", options.build_with_id_strategy(IdStrategy::ContentHash, Size::percent(100.0), Size::pixels(400)));
+}
+
+#[test]
+#[should_panic(expected = "run with UPDATE_SNAPSHOTS=1 to create it")]
+fn missing_snapshot_fails() {
+    let path = std::env::temp_dir().join(format!("echarts-snapshot-{}/missing.txt", std::process::id()));
+    let _ = fs::remove_file(&path);
+    assert_snapshot_with(&path, "line", false);
+}
+
+#[test]
+fn line_endings_are_ignored() {
+    let path = std::env::temp_dir().join(format!("echarts-snapshot-{}/lines.txt", std::process::id()));
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "first\r\nsecond").unwrap();
+    assert_snapshot_with(&path, "first\nsecond\n", false);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "does not match, run with UPDATE_SNAPSHOTS=1")]
+fn changed_options_fail() {
+    let path = std::env::temp_dir().join(format!("echarts-snapshot-{}/changed.json", std::process::id()));
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, serde_json::to_string_pretty(&chart()).unwrap()).unwrap();
+    assert_snapshot_with(&path, &serde_json::to_string_pretty(&chart()).unwrap(), false);
+    assert_snapshot_with(&path, &serde_json::to_string_pretty(&chart().title_str("Revenue".to_string())).unwrap(), false);
+}
@@ -0,0 +1,68 @@
+use apache_echarts_wrapper::options::{
+    EChartOptions, Emphasis, EmphasisFocus, ItemStyle, Label, LabelPosition, LineStyle, LineType,
+    LineTypeKeyword, NamedValuePair, Series, SeriesDataSource, SeriesType,
+};
+use apache_echarts_wrapper::presets::StylePreset;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn series_styles_serialize_in_camel_case() {
+    let series = Series::new(
+        "styled".to_string(),
+        SeriesType::Line,
+        SeriesDataSource::from_named_value_pairs(vec![
+            NamedValuePair::new(1.0, 2.0, "plain".to_string()),
+            NamedValuePair::new(2.0, 5.0, "highlighted".to_string())
+                .item_style(ItemStyle { color: Some(json!("#c23531")), ..Default::default() })
+                .label(Label::shown(LabelPosition::InsideTop)),
+        ]),
+    )
+    .line_style(LineStyle {
+        width: Some(3.0),
+        r#type: Some(LineType::Keyword(LineTypeKeyword::Dashed)),
+        shadow_offset_y: Some(2.0),
+        ..Default::default()
+    })
+    .item_style(ItemStyle { border_width: Some(1.0), border_type: Some(LineType::Dash(vec![4.0, 2.0])), ..Default::default() })
+    .emphasis(Emphasis { focus: Some(EmphasisFocus::Itself), ..Default::default() });
+
+    let chart = EChartOptions::<f64, f64>::default().add_series_direct(series);
+    let value = serde_json::to_value(&chart).unwrap();
+
+    assert_eq!(
+        value["series"][0],
+        json!({
+            "type": "line",
+            "name": "styled",
+            "data": [
+                { "value": [1.0, 2.0], "name": "plain" },
+                {
+                    "value": [2.0, 5.0],
+                    "name": "highlighted",
+                    "itemStyle": { "color": "#c23531" },
+                    "label": { "show": true, "position": "insideTop" }
+                }
+            ],
+            "itemStyle": { "borderWidth": 1.0, "borderType": [4.0, 2.0] },
+            "lineStyle": { "width": 3.0, "type": "dashed", "shadowOffsetY": 2.0 },
+            "emphasis": { "focus": "self" }
+        })
+    );
+}
+
+#[test]
+fn preset_sets_palette_fonts_and_placement() {
+    let chart = EChartOptions::<f64, f64>::default()
+        .enable_legend()
+        .apply_preset(&StylePreset::pastel());
+    let value = serde_json::to_value(&chart).unwrap();
+
+    assert_eq!(value["color"][0], json!("#5b8ff9"));
+    assert_eq!(value["backgroundColor"], json!("#ffffff"));
+    assert_eq!(value["textStyle"], json!({ "color": "#595959", "fontFamily": "sans-serif", "fontSize": 12.0 }));
+    assert_eq!(value["legend"], json!({ "orient": "horizontal", "left": "center", "top": 28.0 }));
+    assert_eq!(value["grid"], json!({ "left": 48.0, "right": 24.0, "top": 64.0, "bottom": 40.0, "containLabel": true }));
+    assert_eq!(value["tooltip"]["trigger"], json!("item"));
+    assert_eq!(value["tooltip"]["backgroundColor"], json!("rgba(255, 255, 255, 0.95)"));
+}
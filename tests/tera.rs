@@ -0,0 +1,17 @@
+#![cfg(feature = "tera")]
+mod common;
+use apache_echarts_wrapper::tera_integration::register;
+use tera::{Context, Tera};
+use crate::common::options_json;
+
+#[test]
+fn tera_filter_renders_options() {
+    let mut tera = Tera::default();
+    register(&mut tera);
+    tera.add_raw_template("page.html", r#"<main>{{ options | echarts_chart(width="50%", id="sales") }}</main>"#).unwrap();
+    let mut context = Context::new();
+    context.insert("options", &options_json());
+    let html = tera.render("page.html", &context).unwrap();
+    assert!(html.contains(r#"<div id="sales" style="width: 50%;height:400px;"></div>"#));
+    assert!(html.contains(r#""data":[["a",1.0],["b",2.0]]"#));
+}
@@ -0,0 +1,70 @@
+use apache_echarts_wrapper::common::Size;
+use apache_echarts_wrapper::options::{EChartOptions, SeriesType, TextStyle};
+use apache_echarts_wrapper::theme::{ChartTheme, Theme};
+use pretty_assertions::assert_eq;
+use sailfish::TemplateSimple;
+use serde_json::{json, Value};
+use std::fs;
+
+#[test]
+fn theme_builder_file_is_unwrapped() {
+    let theme = Theme::from_json(r##"{
+        "version": 1,
+        "themeName": "brand",
+        "theme": {
+            "color": ["#37a2da", "#32c5e9"],
+            "backgroundColor": "#fafafa",
+            "title": { "textStyle": { "color": "#111111" } },
+            "line": { "symbolSize": "4", "smooth": true }
+        }
+    }"##).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&theme).unwrap(),
+        json!({
+            "color": ["#37a2da", "#32c5e9"],
+            "backgroundColor": "#fafafa",
+            "title": { "textStyle": { "color": "#111111" } },
+            "line": { "symbolSize": "4", "smooth": true }
+        })
+    );
+}
+
+#[test]
+fn themes_are_passed_to_init() {
+    let chart = || EChartOptions::<&str, f64>::default()
+        .add_series(SeriesType::Bar, "set".to_string(), vec![("a", 1.0), ("b", 2.0)]);
+
+    let dark = chart().build(Size::pixels(600), Size::pixels(400))
+        .theme(ChartTheme::Dark)
+        .render_once().unwrap();
+    assert!(dark.contains(r#"echarts.init(chartDom, "dark");"#));
+    assert!(!dark.contains("registerTheme"));
+
+    let theme = Theme { color: Some(vec![json!("#ff0000")]), ..Default::default() };
+    let custom = chart().build(Size::pixels(600), Size::pixels(400))
+        .theme(ChartTheme::custom("brand", theme))
+        .render_once().unwrap();
+    assert!(custom.contains(r##"echarts.registerTheme("brand", {"color":["#ff0000"]});"##));
+    assert!(custom.contains(r#"echarts.init(chartDom, "brand");"#));
+}
+
+#[test]
+fn exported_theme_file_is_kept_as_it_is() {
+    let path = "tests/themes/westeros.json";
+    let theme = Theme::from_file(path).unwrap();
+    let exported: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    assert_eq!(serde_json::to_value(&theme).unwrap(), exported);
+}
+
+#[test]
+fn loaded_theme_fields_can_be_changed() {
+    let mut theme = Theme::from_file("tests/themes/westeros.json").unwrap();
+    assert_eq!(theme.color.as_ref().map(Vec::len), Some(6));
+    theme.color = Some(vec![json!("#ff0000")]);
+    theme.text_style = Some(TextStyle { font_size: Some(14.0), ..Default::default() });
+    let value: Value = serde_json::from_str(&serde_json::to_string(&theme).unwrap()).unwrap();
+    assert_eq!(value["color"], json!(["#ff0000"]));
+    assert_eq!(value["textStyle"], json!({ "fontSize": 14.0 }));
+    assert_eq!(value["line"]["smooth"], json!(true));
+}
@@ -1,6 +1,8 @@
+#![cfg(feature = "time_axis")]
 use apache_echarts_wrapper::common::Size;
 use apache_echarts_wrapper::options::{EChartOptions, SeriesType};
 use sailfish::TemplateSimple;
+use serde_json::json;
 use time::macros::{datetime, time};
 use time::Weekday::{Friday, Monday, Thursday, Tuesday};
 
@@ -77,4 +79,12 @@ fn time_test() {
     body.push_str(&chart_date_time.render_once().unwrap());
     body.push_str(&chart_time.render_once().unwrap());
     show_page(body.as_str())
-}
\ No newline at end of file
+}
+
+#[test]
+fn time_of_day_is_serialized_with_seconds() {
+    let chart = EChartOptions::<time::Time, f64>::default()
+        .add_series(SeriesType::Line, "load".to_string(), vec![(time!(9:05:07), 1.0), (time!(23:59), 2.0)]);
+    let value = serde_json::to_value(&chart).unwrap();
+    assert_eq!(value["series"][0]["data"], json!([["09:05:07", 1.0], ["23:59:00", 2.0]]));
+}
@@ -0,0 +1,42 @@
+use apache_echarts_wrapper::common::Size;
+use apache_echarts_wrapper::options::{EChartOptions, SeriesType, Title};
+use apache_echarts_wrapper::timeline::{TimelineFrame, TimelineOptions};
+use pretty_assertions::assert_eq;
+use sailfish::TemplateSimple;
+use serde_json::json;
+
+fn quarters() -> TimelineOptions<&'static str, f64> {
+    let base = EChartOptions::<&str, f64>::default()
+        .add_series(SeriesType::Bar, "revenue".to_string(), vec![("north", 0.0), ("south", 0.0)]);
+    TimelineOptions::new(base)
+        .auto_play(2000, true)
+        .add_frame(TimelineFrame::new("Q1")
+            .title(Title::new("Revenue Q1"))
+            .add_series(vec![("north", 10.0), ("south", 12.0)]))
+        .add_frame(TimelineFrame::new("Q2")
+            .add_series(vec![("north", 14.0), ("south", 9.0)]))
+}
+
+#[test]
+fn frames_become_timeline_options() {
+    let value = serde_json::to_value(quarters()).unwrap();
+
+    assert_eq!(
+        value["baseOption"]["timeline"],
+        json!({ "axisType": "category", "autoPlay": true, "loop": true, "playInterval": 2000, "data": ["Q1", "Q2"] })
+    );
+    assert_eq!(value["baseOption"]["series"][0]["type"], json!("bar"));
+    assert_eq!(
+        value["options"],
+        json!([
+            { "title": { "text": "Revenue Q1" }, "series": [{ "data": [["north", 10.0], ["south", 12.0]] }] },
+            { "series": [{ "data": [["north", 14.0], ["south", 9.0]] }] }
+        ])
+    );
+}
+
+#[test]
+fn timeline_renders_through_chart_template() {
+    let html = quarters().build(Size::pixels(600), Size::pixels(400)).render_once().unwrap();
+    assert!(html.contains(r#"option ={"baseOption":{"timeline":"#));
+}